@@ -0,0 +1,229 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, Member, deserializers, serializers};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Absence {
+    pub id: String,
+    pub member: Member,
+    #[serde(default)]
+    pub reason: Option<AbsenceReason>,
+    pub description: Option<String>,
+    #[serde(deserialize_with = "deserializers::deserialize_space_local_date")]
+    pub since: NaiveDate,
+    #[serde(deserialize_with = "deserializers::deserialize_space_local_date")]
+    pub till: NaiveDate,
+    #[serde(default)]
+    pub available: bool,
+    #[serde(default)]
+    pub approval: Option<AbsenceApproval>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+impl Absence {
+    pub fn covers(&self, date: NaiveDate) -> bool {
+        self.since <= date && date <= self.till
+    }
+
+    // Pending absences count as time off, rejected ones do not.
+    pub fn makes_unavailable_on(&self, date: NaiveDate) -> bool {
+        let rejected = self
+            .approval
+            .as_ref()
+            .is_some_and(|approval| !approval.approved);
+
+        !self.archived && !self.available && !rejected && self.covers(date)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AbsenceReason {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AbsenceApproval {
+    pub approved: bool,
+    #[serde(rename(deserialize = "approvedBy"), default)]
+    pub approved_by: Option<Member>,
+    #[serde(
+        rename(deserialize = "approvedAt"),
+        deserialize_with = "deserializers::deserialize_optional_space_date",
+        default
+    )]
+    pub approved_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct AbsencesApiResponse {
+    data: Vec<Absence>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AbsencesQuery {
+    #[serde(
+        rename(serialize = "member"),
+        serialize_with = "serializers::serialize_optional_profile_identifier"
+    )]
+    pub member_id: Option<String>,
+    #[serde(rename(serialize = "team"))]
+    pub team_id: Option<String>,
+    pub since: Option<NaiveDate>,
+    pub till: Option<NaiveDate>,
+    #[serde(rename(serialize = "$top"))]
+    pub top: usize,
+    #[serde(rename(serialize = "$skip"))]
+    pub skip: usize,
+    #[serde(rename(serialize = "$fields"))]
+    pub fields: String,
+}
+
+impl AbsencesQuery {
+    pub fn new(since: NaiveDate, till: NaiveDate) -> Self {
+        Self {
+            member_id: None,
+            team_id: None,
+            since: Some(since),
+            till: Some(till),
+            top: 100000,
+            skip: 0,
+            fields: "data(id,member(id,username),reason(id,name),description,since,till,available,approval(approved,approvedBy(id,username),approvedAt),archived),next,totalCount".to_string(),
+        }
+    }
+
+    pub fn for_member(member_id: &str, since: NaiveDate, till: NaiveDate) -> Self {
+        Self {
+            member_id: Some(member_id.to_string()),
+            ..Self::new(since, till)
+        }
+    }
+
+    pub fn for_team(team_id: &str, since: NaiveDate, till: NaiveDate) -> Self {
+        Self {
+            team_id: Some(team_id.to_string()),
+            ..Self::new(since, till)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewAbsence {
+    #[serde(
+        rename(serialize = "member"),
+        serialize_with = "serializers::serialize_profile_identifier"
+    )]
+    pub member_id: String,
+    #[serde(rename(serialize = "reason"))]
+    pub reason_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    pub since: NaiveDate,
+    pub till: NaiveDate,
+    pub available: bool,
+}
+
+impl NewAbsence {
+    pub fn new(member_id: &str, reason_id: &str, since: NaiveDate, till: NaiveDate) -> Self {
+        Self {
+            member_id: member_id.to_string(),
+            reason_id: reason_id.to_string(),
+            description: None,
+            icon: None,
+            since,
+            till,
+            available: false,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct AbsenceApprovalRequest {
+    approve: bool,
+}
+
+impl Client {
+    pub async fn get_absences(&self, query: AbsencesQuery) -> Result<Vec<Absence>, Error> {
+        let url = format!("{}/api/http/absences", self.base_url);
+
+        Ok(self
+            .send_request::<_, AbsencesApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_member_absences(
+        &self,
+        member_id: &str,
+        since: NaiveDate,
+        till: NaiveDate,
+    ) -> Result<Vec<Absence>, Error> {
+        self.get_absences(AbsencesQuery::for_member(member_id, since, till))
+            .await
+    }
+
+    pub async fn get_team_absences(
+        &self,
+        team_id: &str,
+        since: NaiveDate,
+        till: NaiveDate,
+    ) -> Result<Vec<Absence>, Error> {
+        self.get_absences(AbsencesQuery::for_team(team_id, since, till))
+            .await
+    }
+
+    pub async fn get_absence_reasons(&self) -> Result<Vec<AbsenceReason>, Error> {
+        let url = format!("{}/api/http/absences/absence-reasons", self.base_url);
+
+        self.send_request::<_, Vec<AbsenceReason>>(&url, ()).await
+    }
+
+    pub async fn create_absence(&self, absence: NewAbsence) -> Result<Absence, Error> {
+        let url = format!("{}/api/http/absences", self.base_url);
+
+        self.send_json_request::<_, Absence>(reqwest::Method::POST, &url, absence)
+            .await
+    }
+
+    pub async fn approve_absence(&self, absence_id: &str) -> Result<(), Error> {
+        self.set_absence_approval(absence_id, true).await
+    }
+
+    pub async fn reject_absence(&self, absence_id: &str) -> Result<(), Error> {
+        self.set_absence_approval(absence_id, false).await
+    }
+
+    pub async fn delete_absence(&self, absence_id: &str) -> Result<(), Error> {
+        let url = format!("{}/api/http/absences/{absence_id}", self.base_url);
+
+        self.send_request_without_response::<()>(reqwest::Method::DELETE, &url, None)
+            .await
+    }
+
+    pub async fn is_member_available(
+        &self,
+        member: &Member,
+        date: NaiveDate,
+    ) -> Result<bool, Error> {
+        Ok(!self
+            .get_member_absences(&member.id, date, date)
+            .await?
+            .iter()
+            .any(|absence| absence.makes_unavailable_on(date)))
+    }
+
+    async fn set_absence_approval(&self, absence_id: &str, approve: bool) -> Result<(), Error> {
+        let url = format!("{}/api/http/absences/{absence_id}/approve", self.base_url);
+
+        self.send_request_without_response(
+            reqwest::Method::PATCH,
+            &url,
+            Some(AbsenceApprovalRequest { approve }),
+        )
+        .await
+    }
+}
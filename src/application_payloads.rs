@@ -0,0 +1,249 @@
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{ChatMessage, Error};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "className")]
+pub enum ApplicationPayload {
+    #[serde(rename = "ListCommandsPayload")]
+    ListCommands(ListCommandsPayload),
+    #[serde(rename = "MessagePayload")]
+    Message(MessagePayload),
+    #[serde(rename = "MenuActionPayload")]
+    MenuAction(MenuActionPayload),
+    #[serde(rename = "AppPublicationCheckPayload")]
+    PublicationCheck(AppPublicationCheck),
+    #[serde(rename = "InitPayload")]
+    Init(InitPayload),
+    // Payload classes this crate doesn't model are acknowledged without a handler call.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ListCommandsPayload {
+    #[serde(rename(deserialize = "clientId"))]
+    pub client_id: String,
+    #[serde(rename(deserialize = "userId"))]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessagePayload {
+    #[serde(rename(deserialize = "clientId"))]
+    pub client_id: String,
+    #[serde(rename(deserialize = "userId"))]
+    pub user_id: String,
+    pub message: MessageContext,
+}
+
+impl MessagePayload {
+    pub fn text(&self) -> Option<&str> {
+        match &self.message.body {
+            ChatMessage::Text { text, .. } => Some(text.trim()),
+            ChatMessage::Block { .. } => None,
+        }
+    }
+
+    // Slash commands arrive as plain messages, the first word being the command name.
+    pub fn command(&self) -> Option<&str> {
+        self.text()?.split_whitespace().next()
+    }
+
+    pub fn arguments(&self) -> Vec<&str> {
+        self.text()
+            .map(|text| text.split_whitespace().skip(1).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessageContext {
+    #[serde(rename(deserialize = "messageId"))]
+    pub message_id: String,
+    #[serde(rename(deserialize = "channelId"))]
+    pub channel_id: String,
+    pub body: ChatMessage,
+    #[serde(rename(deserialize = "createdTime"))]
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MenuActionPayload {
+    #[serde(rename(deserialize = "clientId"))]
+    pub client_id: String,
+    #[serde(rename(deserialize = "userId"))]
+    pub user_id: String,
+    #[serde(rename(deserialize = "menuItemUniqueCode"))]
+    pub menu_item: String,
+    #[serde(default)]
+    pub context: MenuActionContext,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MenuActionContext {
+    #[serde(rename(deserialize = "channelId"), default)]
+    pub channel_id: Option<String>,
+    #[serde(rename(deserialize = "messageId"), default)]
+    pub message_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AppPublicationCheck {
+    #[serde(rename(deserialize = "clientId"))]
+    pub client_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InitPayload {
+    #[serde(rename(deserialize = "clientId"))]
+    pub client_id: String,
+    #[serde(rename(deserialize = "clientSecret"))]
+    pub client_secret: String,
+    #[serde(rename(deserialize = "serverUrl"))]
+    pub server_url: String,
+    #[serde(default)]
+    pub state: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ApplicationCommand {
+    pub name: String,
+    pub description: String,
+}
+
+impl ApplicationCommand {
+    pub fn new(name: &str, description: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Commands {
+    pub commands: Vec<ApplicationCommand>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "className")]
+pub enum MenuActionResult {
+    #[serde(rename = "AppUserActionExecutionResult.Success")]
+    Success,
+    #[serde(rename = "AppUserActionExecutionResult.Failure")]
+    Failure { message: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApplicationResponse {
+    Empty,
+    Commands(Commands),
+    MenuAction(MenuActionResult),
+}
+
+impl ApplicationResponse {
+    // `None` means the endpoint should answer 200 with an empty body.
+    pub fn body(&self) -> Option<String> {
+        match self {
+            Self::Empty => None,
+            Self::Commands(commands) => serde_json::to_string(commands).ok(),
+            Self::MenuAction(result) => serde_json::to_string(result).ok(),
+        }
+    }
+}
+
+pub trait ApplicationHandler: Sync {
+    fn list_commands(&self, payload: ListCommandsPayload) -> impl Future<Output = Commands> + Send;
+
+    fn message(&self, payload: MessagePayload) -> impl Future<Output = ()> + Send;
+
+    fn menu_action(
+        &self,
+        payload: MenuActionPayload,
+    ) -> impl Future<Output = MenuActionResult> + Send {
+        async move {
+            MenuActionResult::Failure {
+                message: format!("Unsupported menu item {}", payload.menu_item),
+            }
+        }
+    }
+
+    fn init(&self, payload: InitPayload) -> impl Future<Output = ()> + Send {
+        let _ = payload;
+        async {}
+    }
+
+    fn publication_check(&self, payload: AppPublicationCheck) -> impl Future<Output = ()> + Send {
+        let _ = payload;
+        async {}
+    }
+}
+
+pub fn parse_application_payload(body: &[u8]) -> Result<ApplicationPayload, Error> {
+    serde_json::from_slice(body).map_err(Error::ApplicationPayload)
+}
+
+// The request should be checked with `WebhookVerifier` first, Space signs application
+// requests the same way it signs webhooks.
+pub async fn handle_application_payload<H: ApplicationHandler>(
+    handler: &H,
+    body: &[u8],
+) -> Result<ApplicationResponse, Error> {
+    Ok(match parse_application_payload(body)? {
+        ApplicationPayload::ListCommands(payload) => {
+            ApplicationResponse::Commands(handler.list_commands(payload).await)
+        }
+        ApplicationPayload::Message(payload) => {
+            handler.message(payload).await;
+            ApplicationResponse::Empty
+        }
+        ApplicationPayload::MenuAction(payload) => {
+            ApplicationResponse::MenuAction(handler.menu_action(payload).await)
+        }
+        ApplicationPayload::PublicationCheck(payload) => {
+            handler.publication_check(payload).await;
+            ApplicationResponse::Empty
+        }
+        ApplicationPayload::Init(payload) => {
+            handler.init(payload).await;
+            ApplicationResponse::Empty
+        }
+        ApplicationPayload::Unknown => ApplicationResponse::Empty,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_arguments() {
+        let payload: MessagePayload = serde_json::from_value(serde_json::json!({
+            "clientId": "client",
+            "userId": "user",
+            "message": {
+                "messageId": "message",
+                "channelId": "channel",
+                "body": { "className": "ChatMessage.Text", "text": "  deploy api  staging " },
+                "createdTime": "2025-06-04T12:00:00Z"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(payload.command(), Some("deploy"));
+        assert_eq!(payload.arguments(), vec!["api", "staging"]);
+    }
+
+    #[test]
+    fn test_response_body() {
+        assert_eq!(ApplicationResponse::Empty.body(), None);
+        assert_eq!(
+            ApplicationResponse::MenuAction(MenuActionResult::Success).body(),
+            Some(r#"{"className":"AppUserActionExecutionResult.Success"}"#.to_string())
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, encode_path_segment};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApplicationIdentifier {
+    Me,
+    Id(String),
+    ClientId(String),
+}
+
+// Only used in URL paths, so the ids are percent-encoded.
+impl fmt::Display for ApplicationIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Me => write!(f, "me"),
+            Self::Id(id) => write!(f, "id:{}", encode_path_segment(id)),
+            Self::ClientId(client_id) => write!(f, "clientId:{}", encode_path_segment(client_id)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Application {
+    pub id: String,
+    pub name: String,
+    #[serde(rename(deserialize = "clientId"))]
+    pub client_id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PermissionContext {
+    Global,
+    Project(String),
+    Channel(String),
+}
+
+impl fmt::Display for PermissionContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Global => write!(f, "global"),
+            Self::Project(id) => write!(f, "project:id:{id}"),
+            Self::Channel(id) => write!(f, "channel:id:{id}"),
+        }
+    }
+}
+
+impl Serialize for PermissionContext {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+// Compared by code, so a known right spelled as `Other` is still equal to its named variant.
+#[derive(Debug, Clone)]
+pub enum Right {
+    ViewProjects,
+    ViewIssues,
+    CreateIssues,
+    EditIssues,
+    DeleteIssues,
+    ViewMessages,
+    PostMessages,
+    ViewProfiles,
+    ViewTeams,
+    ReadRepositories,
+    WriteRepositories,
+    ViewCodeReviews,
+    ViewJobs,
+    ViewDocuments,
+    Other(String),
+}
+
+impl Right {
+    pub fn code(&self) -> &str {
+        match self {
+            Self::ViewProjects => "Project.View",
+            Self::ViewIssues => "Project.Issues.View",
+            Self::CreateIssues => "Project.Issues.Create",
+            Self::EditIssues => "Project.Issues.Edit",
+            Self::DeleteIssues => "Project.Issues.Delete",
+            Self::ViewMessages => "Channel.ViewMessages",
+            Self::PostMessages => "Channel.PostMessages",
+            Self::ViewProfiles => "Profile.View",
+            Self::ViewTeams => "Team.View",
+            Self::ReadRepositories => "Project.Repositories.Read",
+            Self::WriteRepositories => "Project.Repositories.Write",
+            Self::ViewCodeReviews => "Project.CodeReview.View",
+            Self::ViewJobs => "Project.Automation.Jobs.View",
+            Self::ViewDocuments => "Project.Documents.View",
+            Self::Other(code) => code,
+        }
+    }
+}
+
+impl PartialEq for Right {
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Eq for Right {}
+
+impl Hash for Right {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code().hash(state);
+    }
+}
+
+impl fmt::Display for Right {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+// Rights this crate doesn't know about are kept as `Other` instead of failing the whole response.
+impl FromStr for Right {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "Project.View" => Self::ViewProjects,
+            "Project.Issues.View" => Self::ViewIssues,
+            "Project.Issues.Create" => Self::CreateIssues,
+            "Project.Issues.Edit" => Self::EditIssues,
+            "Project.Issues.Delete" => Self::DeleteIssues,
+            "Channel.ViewMessages" => Self::ViewMessages,
+            "Channel.PostMessages" => Self::PostMessages,
+            "Profile.View" => Self::ViewProfiles,
+            "Team.View" => Self::ViewTeams,
+            "Project.Repositories.Read" => Self::ReadRepositories,
+            "Project.Repositories.Write" => Self::WriteRepositories,
+            "Project.CodeReview.View" => Self::ViewCodeReviews,
+            "Project.Automation.Jobs.View" => Self::ViewJobs,
+            "Project.Documents.View" => Self::ViewDocuments,
+            code => Self::Other(code.to_string()),
+        })
+    }
+}
+
+impl Serialize for Right {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Right {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RightStatus {
+    Granted,
+    Pending,
+    Denied,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GrantedRight {
+    #[serde(rename(deserialize = "rightCode"))]
+    pub right: Right,
+    pub status: RightStatus,
+}
+
+impl GrantedRight {
+    pub fn is_granted(&self) -> bool {
+        self.status == RightStatus::Granted
+    }
+}
+
+#[derive(Serialize)]
+struct RightsRequest<'a> {
+    #[serde(rename = "contextIdentifier")]
+    context: &'a PermissionContext,
+    #[serde(rename = "rightCodes")]
+    rights: &'a [Right],
+}
+
+impl Client {
+    pub async fn get_application(
+        &self,
+        application: &ApplicationIdentifier,
+    ) -> Result<Application, Error> {
+        let url = format!("{}/api/http/applications/{application}", self.base_url);
+
+        self.send_request::<_, Application>(&url, ()).await
+    }
+
+    pub async fn get_current_application(&self) -> Result<Application, Error> {
+        self.get_application(&ApplicationIdentifier::Me).await
+    }
+
+    pub async fn get_authorized_rights(
+        &self,
+        application: &ApplicationIdentifier,
+        context: &PermissionContext,
+    ) -> Result<Vec<GrantedRight>, Error> {
+        let url = format!(
+            "{}/api/http/applications/{application}/authorizations/authorized-rights",
+            self.base_url
+        );
+
+        self.send_request::<_, Vec<GrantedRight>>(&url, [("contextIdentifier", context)])
+            .await
+    }
+
+    // Requested rights stay pending until an administrator of the context approves them.
+    pub async fn request_rights(
+        &self,
+        application: &ApplicationIdentifier,
+        context: &PermissionContext,
+        rights: &[Right],
+    ) -> Result<(), Error> {
+        self.send_rights_request(
+            reqwest::Method::PATCH,
+            application,
+            "request-rights",
+            context,
+            rights,
+        )
+        .await
+    }
+
+    pub async fn approve_rights(
+        &self,
+        application: &ApplicationIdentifier,
+        context: &PermissionContext,
+        rights: &[Right],
+    ) -> Result<(), Error> {
+        self.send_rights_request(
+            reqwest::Method::POST,
+            application,
+            "approve-rights",
+            context,
+            rights,
+        )
+        .await
+    }
+
+    pub async fn reject_rights(
+        &self,
+        application: &ApplicationIdentifier,
+        context: &PermissionContext,
+        rights: &[Right],
+    ) -> Result<(), Error> {
+        self.send_rights_request(
+            reqwest::Method::POST,
+            application,
+            "reject-rights",
+            context,
+            rights,
+        )
+        .await
+    }
+
+    pub async fn remove_authorization(
+        &self,
+        application: &ApplicationIdentifier,
+        context: &PermissionContext,
+    ) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/applications/{application}/authorizations/authorized-contexts/{context}",
+            self.base_url
+        );
+
+        self.send_request_without_response::<()>(reqwest::Method::DELETE, &url, None)
+            .await
+    }
+
+    async fn send_rights_request(
+        &self,
+        method: reqwest::Method,
+        application: &ApplicationIdentifier,
+        action: &str,
+        context: &PermissionContext,
+        rights: &[Right],
+    ) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/applications/{application}/authorizations/authorized-rights/{action}",
+            self.base_url
+        );
+
+        self.send_request_without_response(method, &url, Some(RightsRequest { context, rights }))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_right_codes_round_trip() {
+        let rights = [
+            Right::ViewProjects,
+            Right::ViewIssues,
+            Right::CreateIssues,
+            Right::EditIssues,
+            Right::DeleteIssues,
+            Right::ViewMessages,
+            Right::PostMessages,
+            Right::ViewProfiles,
+            Right::ViewTeams,
+            Right::ReadRepositories,
+            Right::WriteRepositories,
+            Right::ViewCodeReviews,
+            Right::ViewJobs,
+            Right::ViewDocuments,
+        ];
+        for right in rights {
+            let parsed = right.to_string().parse::<Right>().unwrap();
+            assert!(!matches!(parsed, Right::Other(_)));
+            assert_eq!(parsed, right);
+        }
+        assert_eq!(
+            "Project.Secrets.View".parse::<Right>().unwrap(),
+            Right::Other("Project.Secrets.View".to_string())
+        );
+        assert_eq!(
+            Right::Other("Project.View".to_string()),
+            Right::ViewProjects
+        );
+    }
+}
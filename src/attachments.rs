@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, encode_path_segment};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Attachment {
+    pub id: String,
+    pub filename: String,
+    pub size: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "className")]
+pub(crate) enum AttachmentDetails {
+    FileAttachment {
+        id: String,
+        filename: String,
+        #[serde(rename = "sizeBytes")]
+        size: u64,
+    },
+    ImageAttachment {
+        id: String,
+        #[serde(default)]
+        name: Option<String>,
+    },
+    #[serde(other)]
+    Unsupported,
+}
+
+impl AttachmentDetails {
+    pub(crate) fn into_attachment(self) -> Option<Attachment> {
+        match self {
+            Self::FileAttachment { id, filename, size } => Some(Attachment { id, filename, size }),
+            Self::ImageAttachment { id, name } => Some(Attachment {
+                filename: name.unwrap_or_else(|| id.clone()),
+                id,
+                size: 0,
+            }),
+            Self::Unsupported => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct FileAttachmentRequest<'a> {
+    #[serde(rename = "className")]
+    class_name: &'static str,
+    id: &'a str,
+    filename: &'a str,
+    #[serde(rename = "sizeBytes")]
+    size: u64,
+}
+
+#[derive(Serialize)]
+struct AttachmentsRequest<'a> {
+    attachments: Vec<FileAttachmentRequest<'a>>,
+}
+
+#[derive(Serialize)]
+struct UploadRequest {
+    #[serde(rename = "storagePrefix")]
+    storage_prefix: &'static str,
+}
+
+impl Client {
+    pub async fn download_attachment(&self, attachment_id: &str) -> Result<Vec<u8>, Error> {
+        let url = format!("{}/d/{attachment_id}", self.base_url);
+
+        let response = self
+            .request(reqwest::Method::GET, &url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(Error::Request)?;
+
+        Ok(response
+            .bytes()
+            .await
+            .map_err(Error::ResponseParse)?
+            .to_vec())
+    }
+
+    // Uploading takes two steps, Space first hands out an upload path and the file is then
+    // stored under it, the returned id is what attachments refer to.
+    pub async fn upload_attachment(
+        &self,
+        filename: &str,
+        content: Vec<u8>,
+    ) -> Result<Attachment, Error> {
+        let url = format!("{}/api/http/uploads", self.base_url);
+
+        let size = content.len() as u64;
+        let upload_path = self
+            .send_json_request::<_, String>(
+                reqwest::Method::POST,
+                &url,
+                UploadRequest {
+                    storage_prefix: "file",
+                },
+            )
+            .await?;
+        let upload_url = format!(
+            "{}{upload_path}/{}",
+            self.base_url,
+            encode_path_segment(filename)
+        );
+
+        let id = self
+            .request(reqwest::Method::PUT, &upload_url)
+            .body(content)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(Error::Request)?
+            .text()
+            .await
+            .map_err(Error::ResponseParse)?;
+
+        Ok(Attachment {
+            id: id.trim().to_string(),
+            filename: filename.to_string(),
+            size,
+        })
+    }
+
+    pub async fn add_issue_attachments(
+        &self,
+        project_id: &str,
+        issue_id: &str,
+        attachments: &[Attachment],
+    ) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/planning/issues/id:{issue_id}/attachments",
+            self.base_url
+        );
+
+        let body = AttachmentsRequest {
+            attachments: attachments
+                .iter()
+                .map(|attachment| FileAttachmentRequest {
+                    class_name: "FileAttachment",
+                    id: &attachment.id,
+                    filename: &attachment.filename,
+                    size: attachment.size,
+                })
+                .collect(),
+        };
+
+        self.send_request_without_response(reqwest::Method::POST, &url, Some(body))
+            .await
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, deserializers};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Job {
+    pub id: String,
+    pub name: String,
+    #[serde(rename(deserialize = "repoName"))]
+    pub repository: String,
+    #[serde(rename(deserialize = "archive"), default)]
+    pub archived: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JobExecution {
+    pub id: String,
+    #[serde(rename(deserialize = "executionNumber"))]
+    pub number: usize,
+    #[serde(rename(deserialize = "jobId"))]
+    pub job_id: String,
+    #[serde(rename(deserialize = "jobName"))]
+    pub job_name: String,
+    #[serde(default)]
+    pub branch: Option<String>,
+    #[serde(rename(deserialize = "executionStatus"))]
+    pub status: ExecutionStatus,
+    #[serde(
+        rename(deserialize = "triggerTime"),
+        deserialize_with = "deserializers::deserialize_timestamp_millis"
+    )]
+    pub triggered_at: DateTime<Utc>,
+    #[serde(
+        rename(deserialize = "finishTime"),
+        deserialize_with = "deserializers::deserialize_optional_timestamp_millis",
+        default
+    )]
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StepExecution {
+    pub id: String,
+    pub name: String,
+    #[serde(rename(deserialize = "executionStatus"))]
+    pub status: ExecutionStatus,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExecutionStatus {
+    Scheduled,
+    Pending,
+    Running,
+    Finished,
+    Failed,
+    Terminated,
+    Hung,
+    Skipped,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ExecutionStatus {
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            Self::Finished | Self::Failed | Self::Terminated | Self::Hung | Self::Skipped
+        )
+    }
+
+    pub fn is_successful(self) -> bool {
+        self == Self::Finished
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JobsQuery {
+    #[serde(skip_serializing)]
+    pub project_id: String,
+    #[serde(rename(serialize = "repoFilter"))]
+    pub repository: Option<String>,
+    #[serde(rename(serialize = "branchFilter"))]
+    pub branch: Option<String>,
+    #[serde(rename(serialize = "$top"))]
+    pub top: usize,
+    #[serde(rename(serialize = "$skip"))]
+    pub skip: usize,
+    #[serde(rename(serialize = "$fields"))]
+    pub fields: String,
+}
+
+impl JobsQuery {
+    pub fn new(project_id: &str) -> Self {
+        Self {
+            project_id: project_id.to_string(),
+            repository: None,
+            branch: None,
+            top: 100000,
+            skip: 0,
+            fields: "data(id,name,repoName,archive),next,totalCount".to_string(),
+        }
+    }
+
+    pub fn for_repository(project_id: &str, repository: &str) -> Self {
+        Self {
+            repository: Some(repository.to_string()),
+            ..Self::new(project_id)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct JobsApiResponse {
+    data: Vec<Job>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct StepExecutionsApiResponse {
+    data: Vec<StepExecution>,
+}
+
+#[derive(Deserialize)]
+struct StepLogApiResponse {
+    lines: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct StartJobApiResponse {
+    #[serde(rename = "executionId")]
+    execution_id: String,
+}
+
+#[derive(Serialize)]
+struct StartJobRequest<'a> {
+    branch: GitBranchSpec<'a>,
+    parameters: Vec<JobParameter<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "className", rename = "GitBranchSpec.Head")]
+struct GitBranchSpec<'a> {
+    #[serde(rename = "ref")]
+    head: &'a str,
+}
+
+#[derive(Serialize)]
+struct JobParameter<'a> {
+    name: &'a str,
+    value: &'a str,
+}
+
+const JOB_EXECUTION_FIELDS: &str =
+    "id,executionNumber,jobId,jobName,branch,executionStatus,triggerTime,finishTime";
+
+impl Client {
+    pub async fn get_jobs(&self, query: JobsQuery) -> Result<Vec<Job>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{}/automation/jobs",
+            self.base_url, query.project_id
+        );
+
+        Ok(self
+            .send_request::<_, JobsApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_repository_jobs(
+        &self,
+        project_id: &str,
+        repository: &str,
+    ) -> Result<Vec<Job>, Error> {
+        self.get_jobs(JobsQuery::for_repository(project_id, repository))
+            .await
+    }
+
+    // Returns the id of the new execution, which can be polled with `get_job_execution`.
+    pub async fn start_job(
+        &self,
+        project_id: &str,
+        job_id: &str,
+        branch: &str,
+        parameters: &HashMap<String, String>,
+    ) -> Result<String, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/automation/jobs/{job_id}/start",
+            self.base_url
+        );
+
+        let mut parameters: Vec<JobParameter> = parameters
+            .iter()
+            .map(|(name, value)| JobParameter { name, value })
+            .collect();
+        parameters.sort_by_key(|parameter| parameter.name);
+
+        let body = StartJobRequest {
+            branch: GitBranchSpec { head: branch },
+            parameters,
+        };
+
+        Ok(self
+            .send_json_request::<_, StartJobApiResponse>(reqwest::Method::POST, &url, body)
+            .await?
+            .execution_id)
+    }
+
+    pub async fn get_job_execution(&self, execution_id: &str) -> Result<JobExecution, Error> {
+        let url = format!(
+            "{}/api/http/projects/automation/graph-executions/{execution_id}",
+            self.base_url
+        );
+
+        let query = HashMap::from([("$fields", JOB_EXECUTION_FIELDS)]);
+
+        self.send_request::<_, JobExecution>(&url, query).await
+    }
+
+    pub async fn get_execution_steps(
+        &self,
+        execution_id: &str,
+    ) -> Result<Vec<StepExecution>, Error> {
+        let url = format!(
+            "{}/api/http/projects/automation/graph-executions/{execution_id}/steps",
+            self.base_url
+        );
+
+        let query = HashMap::from([("$fields", "data(id,name,executionStatus)")]);
+
+        Ok(self
+            .send_request::<_, StepExecutionsApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_step_log(&self, step_execution_id: &str) -> Result<Vec<String>, Error> {
+        let url = format!(
+            "{}/api/http/projects/automation/step-executions/{step_execution_id}/log",
+            self.base_url
+        );
+
+        Ok(self
+            .send_request::<_, StepLogApiResponse>(&url, ())
+            .await?
+            .lines)
+    }
+
+    pub async fn cancel_job_execution(&self, execution_id: &str) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/projects/automation/graph-executions/{execution_id}/stop",
+            self.base_url
+        );
+
+        self.send_request_without_response::<()>(reqwest::Method::POST, &url, None)
+            .await
+    }
+}
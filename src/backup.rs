@@ -0,0 +1,740 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Attachment, ChannelIdentifier, ChatMessage, Client, CustomField, CustomFieldInput,
+    CustomFieldInputValue, CustomFieldType, CustomFieldValue, Error, ISSUE_FIELDS, Issue,
+    IssueStatus, IssueTag, IssuesQuery, Member, MessagesQuery, NewCustomField, NewIssue,
+    NewProject, deserializers,
+};
+
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+const BACKUP_PAGE_SIZE: usize = 500;
+const MANIFEST_FILE: &str = "manifest.json";
+const PROJECTS_FILE: &str = "projects.json";
+const RESTORE_STATE_FILE: &str = "restore-state.json";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BackupSummary {
+    pub projects: usize,
+    pub issues: usize,
+    pub messages: usize,
+    pub attachments: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct RestoreSummary {
+    pub projects: usize,
+    pub tags: usize,
+    pub custom_fields: usize,
+    pub issues: usize,
+    pub messages: usize,
+    pub attachments: usize,
+    pub remapped_statuses: Vec<RemappedStatus>,
+}
+
+// An archived status the target project has no status of the same name for.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RemappedStatus {
+    pub project_key: String,
+    pub archived_status: String,
+    pub target_status: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RestoreOptions {
+    // Profiles differ between organizations, assignees without a mapping are left unassigned.
+    pub profile_ids: HashMap<String, String>,
+}
+
+impl RestoreOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ArchivedProject {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ArchivedIssue {
+    pub id: String,
+    pub number: usize,
+    pub title: String,
+    pub description: Option<String>,
+    pub status: String,
+    pub assignee: Option<Member>,
+    pub created_by: Member,
+    pub created_at: DateTime<Utc>,
+    pub tag_ids: Vec<String>,
+    pub attachments: Vec<Attachment>,
+    // Values by field id.
+    pub custom_fields: BTreeMap<String, CustomFieldValue>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ArchivedMessage {
+    pub id: String,
+    pub author: Member,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ArchivedTag {
+    pub id: String,
+    pub name: String,
+    pub parent_id: Option<String>,
+}
+
+impl From<IssueTag> for ArchivedTag {
+    fn from(tag: IssueTag) -> Self {
+        Self {
+            id: tag.id,
+            name: tag.name,
+            parent_id: tag.parent_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Manifest {
+    format_version: u32,
+    source: String,
+    started_at: DateTime<Utc>,
+    completed_at: Option<DateTime<Utc>>,
+}
+
+// Maps source ids to target ids, saved after every change so that a restore can be resumed.
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct RestoreState {
+    target: String,
+    projects: HashMap<String, String>,
+    tags: HashMap<String, String>,
+    custom_fields: HashMap<String, String>,
+    enum_values: HashMap<String, String>,
+    issues: HashMap<String, String>,
+    attached: HashSet<String>,
+    messages: HashMap<String, usize>,
+}
+
+#[derive(Deserialize)]
+struct BackupIssue {
+    #[serde(flatten)]
+    issue: Issue,
+    #[serde(deserialize_with = "deserializers::deserialize_ids", default)]
+    tags: Vec<String>,
+    #[serde(deserialize_with = "deserializers::deserialize_attachments", default)]
+    attachments: Vec<Attachment>,
+    #[serde(rename = "customFields", default)]
+    custom_fields: BTreeMap<String, CustomFieldValue>,
+}
+
+#[derive(Deserialize)]
+struct BackupIssuesApiResponse {
+    data: Vec<BackupIssue>,
+}
+
+struct Archive {
+    root: PathBuf,
+}
+
+impl Archive {
+    fn path(&self, relative: &str) -> PathBuf {
+        self.root.join(relative)
+    }
+
+    fn exists(&self, relative: &str) -> bool {
+        self.path(relative).exists()
+    }
+
+    fn read<T: DeserializeOwned>(&self, relative: &str) -> Result<Option<T>, Error> {
+        let path = self.path(relative);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read(&path).map_err(Error::Archive)?;
+        serde_json::from_slice(&content)
+            .map(Some)
+            .map_err(|error| Error::Archive(error.into()))
+    }
+
+    fn require<T: DeserializeOwned>(&self, relative: &str) -> Result<T, Error> {
+        self.read(relative)?.ok_or_else(|| {
+            Error::UnsupportedArchive(format!("{relative} is missing from the archive"))
+        })
+    }
+
+    fn read_bytes(&self, relative: &str) -> Result<Vec<u8>, Error> {
+        fs::read(self.path(relative)).map_err(Error::Archive)
+    }
+
+    fn write<T: Serialize>(&self, relative: &str, value: &T) -> Result<(), Error> {
+        let content =
+            serde_json::to_vec_pretty(value).map_err(|error| Error::Archive(error.into()))?;
+        self.write_bytes(relative, &content)
+    }
+
+    // Files are renamed into place, an interrupted write never leaves a half-written file that
+    // a resumed backup would take for a finished one.
+    fn write_bytes(&self, relative: &str, content: &[u8]) -> Result<(), Error> {
+        let path = self.path(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::Archive)?;
+        }
+
+        let temporary = path.with_extension("partial");
+        fs::write(&temporary, content).map_err(Error::Archive)?;
+        fs::rename(&temporary, &path).map_err(Error::Archive)
+    }
+}
+
+fn check_format_version(manifest: &Manifest) -> Result<(), Error> {
+    if manifest.format_version == BACKUP_FORMAT_VERSION {
+        Ok(())
+    } else {
+        Err(Error::UnsupportedArchive(format!(
+            "format version {} is not supported, expected {BACKUP_FORMAT_VERSION}",
+            manifest.format_version
+        )))
+    }
+}
+
+// Falls back to a status with the same resolved flag and then to the first one, the second
+// value tells whether the status was matched by name.
+fn find_status<'a>(
+    archived_statuses: &[IssueStatus],
+    target_statuses: &'a [IssueStatus],
+    name: &str,
+) -> Option<(&'a IssueStatus, bool)> {
+    if let Some(status) = target_statuses
+        .iter()
+        .find(|status| status.name.eq_ignore_ascii_case(name))
+    {
+        return Some((status, true));
+    }
+
+    let resolved = archived_statuses
+        .iter()
+        .find(|status| status.name == name)
+        .is_some_and(|status| status.resolved);
+
+    target_statuses
+        .iter()
+        .find(|status| status.resolved == resolved && !status.archived)
+        .or_else(|| target_statuses.first())
+        .map(|status| (status, false))
+}
+
+// Values that only make sense in the source organization, such as unmapped profiles, are dropped.
+fn custom_field_input(
+    value: &CustomFieldValue,
+    state: &RestoreState,
+    profile_ids: &HashMap<String, String>,
+) -> Option<CustomFieldInputValue> {
+    match value {
+        CustomFieldValue::String { value } => {
+            value.as_ref().map(|value| CustomFieldInputValue::String {
+                value: Some(value.clone()),
+            })
+        }
+        CustomFieldValue::Integer { value } => {
+            value.map(|value| CustomFieldInputValue::Integer { value: Some(value) })
+        }
+        CustomFieldValue::Boolean { value } => {
+            value.map(|value| CustomFieldInputValue::Boolean { value: Some(value) })
+        }
+        CustomFieldValue::Enum { value } => value
+            .as_ref()
+            .and_then(|value| state.enum_values.get(&value.id))
+            .map(|enum_value_id| CustomFieldInputValue::Enum {
+                enum_value_id: Some(enum_value_id.clone()),
+            }),
+        CustomFieldValue::Profile { profile } => profile
+            .as_ref()
+            .and_then(|profile| profile_ids.get(&profile.id))
+            .map(|profile_id| CustomFieldInputValue::Profile {
+                profile_id: Some(format!("id:{profile_id}")),
+            }),
+        CustomFieldValue::Unsupported => None,
+    }
+}
+
+impl Client {
+    // Running it again on the same directory resumes an interrupted backup, everything already
+    // in the archive is kept and only the missing parts are fetched.
+    pub async fn backup(&self, directory: &Path) -> Result<BackupSummary, Error> {
+        let archive = Archive {
+            root: directory.to_path_buf(),
+        };
+
+        let mut manifest = match archive.read::<Manifest>(MANIFEST_FILE)? {
+            Some(manifest) => {
+                check_format_version(&manifest)?;
+                if manifest.source != self.base_url {
+                    return Err(Error::UnsupportedArchive(format!(
+                        "archive belongs to {}",
+                        manifest.source
+                    )));
+                }
+                manifest
+            }
+            None => {
+                let manifest = Manifest {
+                    format_version: BACKUP_FORMAT_VERSION,
+                    source: self.base_url.clone(),
+                    started_at: Utc::now(),
+                    completed_at: None,
+                };
+                archive.write(MANIFEST_FILE, &manifest)?;
+                manifest
+            }
+        };
+
+        let projects = match archive.read::<Vec<ArchivedProject>>(PROJECTS_FILE)? {
+            Some(projects) => projects,
+            None => {
+                let projects: Vec<ArchivedProject> = self
+                    .get_projects()
+                    .await?
+                    .into_iter()
+                    .map(|project| ArchivedProject {
+                        id: project.id,
+                        key: project.key,
+                        name: project.name,
+                    })
+                    .collect();
+                archive.write(PROJECTS_FILE, &projects)?;
+                projects
+            }
+        };
+
+        let mut summary = BackupSummary::default();
+        for project in &projects {
+            let project_dir = format!("projects/{}", project.id);
+
+            let statuses_file = format!("{project_dir}/statuses.json");
+            if !archive.exists(&statuses_file) {
+                let statuses = self.get_issue_statuses(&project.id).await?;
+                archive.write(&statuses_file, &statuses)?;
+            }
+
+            let tags_file = format!("{project_dir}/tags.json");
+            if !archive.exists(&tags_file) {
+                let tags: Vec<ArchivedTag> = self
+                    .get_issue_tags(&project.id)
+                    .await?
+                    .into_iter()
+                    .map(ArchivedTag::from)
+                    .collect();
+                archive.write(&tags_file, &tags)?;
+            }
+
+            let custom_fields_file = format!("{project_dir}/custom-fields.json");
+            if !archive.exists(&custom_fields_file) {
+                let custom_fields = self.get_issue_custom_fields(&project.id).await?;
+                archive.write(&custom_fields_file, &custom_fields)?;
+            }
+
+            let issues_file = format!("{project_dir}/issues.json");
+            let issues = match archive.read::<Vec<ArchivedIssue>>(&issues_file)? {
+                Some(issues) => issues,
+                None => {
+                    let issues = self.get_backup_issues(&project.id).await?;
+                    archive.write(&issues_file, &issues)?;
+                    issues
+                }
+            };
+
+            for issue in &issues {
+                let messages_file = format!("{project_dir}/messages/{}.json", issue.id);
+                let messages = match archive.read::<Vec<ArchivedMessage>>(&messages_file)? {
+                    Some(messages) => messages,
+                    None => {
+                        let messages: Vec<ArchivedMessage> = self
+                            .get_issue_messages(MessagesQuery::new(&issue.id))
+                            .await?
+                            .into_iter()
+                            .map(|message| ArchivedMessage {
+                                id: message.id,
+                                author: message.author,
+                                text: message.text,
+                                created_at: message.created_at,
+                            })
+                            .collect();
+                        archive.write(&messages_file, &messages)?;
+                        messages
+                    }
+                };
+                summary.messages += messages.len();
+
+                for attachment in &issue.attachments {
+                    let attachment_file = format!("attachments/{}", attachment.id);
+                    if !archive.exists(&attachment_file) {
+                        let content = self.download_attachment(&attachment.id).await?;
+                        archive.write_bytes(&attachment_file, &content)?;
+                    }
+                    summary.attachments += 1;
+                }
+            }
+
+            summary.projects += 1;
+            summary.issues += issues.len();
+        }
+
+        manifest.completed_at = Some(Utc::now());
+        archive.write(MANIFEST_FILE, &manifest)?;
+
+        Ok(summary)
+    }
+
+    // Projects are matched by key and created when missing, statuses by name and tags by name
+    // and parent, statuses without a match are reported in the summary. Messages are posted by
+    // the restoring account, so the original author and time are written into the message itself.
+    //
+    // Resuming is at-least-once: the restore state is saved after each object is created, so an
+    // interruption between a request and that save creates the issue, attachment or message
+    // again on the next run.
+    pub async fn restore_backup(
+        &self,
+        directory: &Path,
+        options: RestoreOptions,
+    ) -> Result<RestoreSummary, Error> {
+        let archive = Archive {
+            root: directory.to_path_buf(),
+        };
+
+        let manifest: Manifest = archive.require(MANIFEST_FILE)?;
+        check_format_version(&manifest)?;
+        if manifest.completed_at.is_none() {
+            return Err(Error::UnsupportedArchive(
+                "backup is incomplete, resume it before restoring".to_string(),
+            ));
+        }
+
+        let mut state = archive
+            .read::<RestoreState>(RESTORE_STATE_FILE)?
+            .unwrap_or_else(|| RestoreState {
+                target: self.base_url.clone(),
+                ..RestoreState::default()
+            });
+        if state.target != self.base_url {
+            return Err(Error::UnsupportedArchive(format!(
+                "archive is being restored into {}",
+                state.target
+            )));
+        }
+
+        let projects: Vec<ArchivedProject> = archive.require(PROJECTS_FILE)?;
+        let target_projects = self.get_projects().await?;
+
+        let mut summary = RestoreSummary::default();
+        for project in &projects {
+            let project_dir = format!("projects/{}", project.id);
+
+            let project_id = if let Some(project_id) = state.projects.get(&project.id) {
+                project_id.clone()
+            } else {
+                let project_id = match target_projects
+                    .iter()
+                    .find(|candidate| candidate.key == project.key)
+                {
+                    Some(existing) => existing.id.clone(),
+                    None => {
+                        summary.projects += 1;
+                        self.create_project(NewProject::new(&project.key, &project.name))
+                            .await?
+                            .id
+                    }
+                };
+                state
+                    .projects
+                    .insert(project.id.clone(), project_id.clone());
+                archive.write(RESTORE_STATE_FILE, &state)?;
+                project_id
+            };
+
+            summary.tags += self
+                .restore_tags(&archive, &mut state, &project_dir, &project_id)
+                .await?;
+            summary.custom_fields += self
+                .restore_custom_fields(&archive, &mut state, &project_dir, &project_id)
+                .await?;
+
+            let archived_statuses: Vec<IssueStatus> =
+                archive.require(&format!("{project_dir}/statuses.json"))?;
+            let target_statuses = self.get_issue_statuses(&project_id).await?;
+
+            // Creating issues in number order keeps the numbering when the target project is empty.
+            let mut issues: Vec<ArchivedIssue> =
+                archive.require(&format!("{project_dir}/issues.json"))?;
+            issues.sort_by_key(|issue| issue.number);
+
+            for issue in &issues {
+                let issue_id = if let Some(issue_id) = state.issues.get(&issue.id) {
+                    issue_id.clone()
+                } else {
+                    let (status, matched) =
+                        find_status(&archived_statuses, &target_statuses, &issue.status)
+                            .ok_or_else(|| {
+                                Error::UnsupportedArchive(format!(
+                                    "project {} has no issue statuses",
+                                    project.key
+                                ))
+                            })?;
+                    if !matched
+                        && !summary.remapped_statuses.iter().any(|remapped| {
+                            remapped.project_key == project.key
+                                && remapped.archived_status == issue.status
+                        })
+                    {
+                        summary.remapped_statuses.push(RemappedStatus {
+                            project_key: project.key.clone(),
+                            archived_status: issue.status.clone(),
+                            target_status: status.name.clone(),
+                        });
+                    }
+
+                    let mut new_issue = NewIssue::new(&project_id, &issue.title, &status.id);
+                    new_issue.description = issue.description.clone();
+                    new_issue.assignee_id = issue
+                        .assignee
+                        .as_ref()
+                        .and_then(|assignee| options.profile_ids.get(&assignee.id).cloned());
+                    new_issue.tag_ids = issue
+                        .tag_ids
+                        .iter()
+                        .filter_map(|tag_id| state.tags.get(tag_id).cloned())
+                        .collect();
+                    new_issue.custom_fields = issue
+                        .custom_fields
+                        .iter()
+                        .filter_map(|(field_id, value)| {
+                            Some(CustomFieldInput {
+                                field_id: state.custom_fields.get(field_id)?.clone(),
+                                value: custom_field_input(value, &state, &options.profile_ids)?,
+                            })
+                        })
+                        .collect();
+
+                    let issue_id = self.create_issue(new_issue).await?.id;
+                    state.issues.insert(issue.id.clone(), issue_id.clone());
+                    archive.write(RESTORE_STATE_FILE, &state)?;
+                    summary.issues += 1;
+                    issue_id
+                };
+
+                if !issue.attachments.is_empty() && !state.attached.contains(&issue.id) {
+                    let mut attachments = Vec::with_capacity(issue.attachments.len());
+                    for attachment in &issue.attachments {
+                        let content =
+                            archive.read_bytes(&format!("attachments/{}", attachment.id))?;
+                        attachments.push(
+                            self.upload_attachment(&attachment.filename, content)
+                                .await?,
+                        );
+                    }
+                    self.add_issue_attachments(&project_id, &issue_id, &attachments)
+                        .await?;
+
+                    state.attached.insert(issue.id.clone());
+                    archive.write(RESTORE_STATE_FILE, &state)?;
+                    summary.attachments += attachments.len();
+                }
+
+                let messages: Vec<ArchivedMessage> = archive
+                    .read(&format!("{project_dir}/messages/{}.json", issue.id))?
+                    .unwrap_or_default();
+                let posted = state.messages.get(&issue.id).copied().unwrap_or(0);
+                for message in messages.iter().skip(posted) {
+                    let text = format!(
+                        "**{}** · {}\n\n{}",
+                        message.author.username,
+                        message.created_at.format("%Y-%m-%d %H:%M UTC"),
+                        message.text
+                    );
+                    self.send_message(
+                        ChannelIdentifier::Issue(issue_id.clone()),
+                        ChatMessage::markdown(&text),
+                    )
+                    .await?;
+
+                    *state.messages.entry(issue.id.clone()).or_default() += 1;
+                    archive.write(RESTORE_STATE_FILE, &state)?;
+                    summary.messages += 1;
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+
+    async fn get_backup_issues(&self, project_id: &str) -> Result<Vec<ArchivedIssue>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/planning/issues",
+            self.base_url
+        );
+
+        let mut query = IssuesQuery::new(project_id);
+        query.top = BACKUP_PAGE_SIZE;
+        query.fields = format!(
+            "data({ISSUE_FIELDS},tags(id),attachments(details),customFields),next,totalCount"
+        );
+
+        let mut issues = vec![];
+        loop {
+            let page = self
+                .send_request::<_, BackupIssuesApiResponse>(&url, query.clone())
+                .await?
+                .data;
+            let page_size = page.len();
+
+            issues.extend(page.into_iter().map(|entry| ArchivedIssue {
+                id: entry.issue.id,
+                number: entry.issue.number,
+                title: entry.issue.title,
+                description: entry.issue.description,
+                status: entry.issue.status,
+                assignee: entry.issue.assignee,
+                created_by: entry.issue.created_by,
+                created_at: entry.issue.created_at,
+                tag_ids: entry.tags,
+                attachments: entry.attachments,
+                custom_fields: entry.custom_fields,
+            }));
+
+            if page_size < query.top {
+                return Ok(issues);
+            }
+            query.skip += page_size;
+        }
+    }
+
+    // Parents are restored before their children, tags whose parent never shows up are skipped.
+    async fn restore_tags(
+        &self,
+        archive: &Archive,
+        state: &mut RestoreState,
+        project_dir: &str,
+        project_id: &str,
+    ) -> Result<usize, Error> {
+        let mut pending: Vec<ArchivedTag> = archive.require(&format!("{project_dir}/tags.json"))?;
+        pending.retain(|tag| !state.tags.contains_key(&tag.id));
+        if pending.is_empty() {
+            return Ok(0);
+        }
+
+        let mut target_tags = self.get_issue_tags(project_id).await?;
+        let mut created = 0;
+        loop {
+            let (ready, waiting): (Vec<ArchivedTag>, Vec<ArchivedTag>) =
+                pending.into_iter().partition(|tag| {
+                    tag.parent_id
+                        .as_ref()
+                        .is_none_or(|parent_id| state.tags.contains_key(parent_id))
+                });
+            if ready.is_empty() {
+                return Ok(created);
+            }
+
+            for tag in ready {
+                let parent_id = tag
+                    .parent_id
+                    .as_ref()
+                    .and_then(|parent_id| state.tags.get(parent_id).cloned());
+                let existing = target_tags.iter().find(|candidate| {
+                    candidate.name == tag.name && candidate.parent_id == parent_id
+                });
+
+                let tag_id = match existing {
+                    Some(existing) => existing.id.clone(),
+                    None => {
+                        let new_tag = self
+                            .create_issue_tag(project_id, &tag.name, parent_id.as_deref())
+                            .await?;
+                        created += 1;
+                        let tag_id = new_tag.id.clone();
+                        target_tags.push(new_tag);
+                        tag_id
+                    }
+                };
+
+                state.tags.insert(tag.id, tag_id);
+                archive.write(RESTORE_STATE_FILE, state)?;
+            }
+            pending = waiting;
+        }
+    }
+
+    // Fields are matched by name and type and created when missing, enum values by name.
+    // Values of enum options the target field lacks are not restored.
+    async fn restore_custom_fields(
+        &self,
+        archive: &Archive,
+        state: &mut RestoreState,
+        project_dir: &str,
+        project_id: &str,
+    ) -> Result<usize, Error> {
+        let mut pending: Vec<CustomField> =
+            archive.require(&format!("{project_dir}/custom-fields.json"))?;
+        pending.retain(|field| {
+            field.field_type != CustomFieldType::Unsupported
+                && !state.custom_fields.contains_key(&field.id)
+        });
+        if pending.is_empty() {
+            return Ok(0);
+        }
+
+        let target_fields = self.get_issue_custom_fields(project_id).await?;
+        let mut created = 0;
+        for field in pending {
+            let existing = target_fields.iter().find(|candidate| {
+                candidate.name == field.name && candidate.field_type == field.field_type
+            });
+
+            let target = match existing {
+                Some(existing) => existing.clone(),
+                None => {
+                    let new_field = NewCustomField {
+                        enum_values: field
+                            .enum_values
+                            .iter()
+                            .map(|enum_value| enum_value.value.clone())
+                            .collect(),
+                        ..NewCustomField::new(&field.name, field.field_type)
+                    };
+                    created += 1;
+                    self.create_issue_custom_field(project_id, new_field)
+                        .await?
+                }
+            };
+
+            for enum_value in &field.enum_values {
+                if let Some(target_value) = target
+                    .enum_values
+                    .iter()
+                    .find(|candidate| candidate.value == enum_value.value)
+                {
+                    state
+                        .enum_values
+                        .insert(enum_value.id.clone(), target_value.id.clone());
+                }
+            }
+            state.custom_fields.insert(field.id, target.id);
+            archive.write(RESTORE_STATE_FILE, state)?;
+        }
+
+        Ok(created)
+    }
+}
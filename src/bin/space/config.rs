@@ -0,0 +1,113 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::CliError;
+
+#[derive(Deserialize, Debug, Default)]
+struct ConfigFile {
+    url: Option<String>,
+    token: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Config {
+    pub url: String,
+    pub token: String,
+}
+
+impl Config {
+    // Flags and environment variables win over the config file, the default file is optional
+    // while an explicitly given one has to exist.
+    pub fn load(
+        url: Option<String>,
+        token: Option<String>,
+        path: Option<&Path>,
+    ) -> Result<Self, CliError> {
+        let file = match path {
+            Some(path) => Some(read_config_file(path)?),
+            None => default_config_path()
+                .filter(|path| path.exists())
+                .map(|path| read_config_file(&path))
+                .transpose()?,
+        };
+
+        Self::merge(url, token, file.unwrap_or_default())
+    }
+
+    fn merge(
+        url: Option<String>,
+        token: Option<String>,
+        file: ConfigFile,
+    ) -> Result<Self, CliError> {
+        let url = non_empty(url).or(file.url).ok_or_else(|| {
+            CliError::Config(
+                "Space URL is not set, use --url, SPACE_URL or the config file".to_string(),
+            )
+        })?;
+        let token = non_empty(token).or(file.token).ok_or_else(|| {
+            CliError::Config(
+                "Space token is not set, use --token, SPACE_TOKEN or the config file".to_string(),
+            )
+        })?;
+
+        Ok(Self {
+            url: url.trim_end_matches('/').to_string(),
+            token,
+        })
+    }
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.trim().is_empty())
+}
+
+fn default_config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("space").join("config.toml"))
+}
+
+fn read_config_file(path: &Path) -> Result<ConfigFile, CliError> {
+    let content = fs::read_to_string(path)
+        .map_err(|error| CliError::Config(format!("cannot read {}: {error}", path.display())))?;
+
+    toml::from_str(&content)
+        .map_err(|error| CliError::Config(format!("cannot parse {}: {error}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arguments_override_config_file() {
+        let file: ConfigFile = toml::from_str(
+            r#"
+            url = "https://file.jetbrains.space/"
+            token = "file-token"
+            "#,
+        )
+        .unwrap();
+
+        let config = Config::merge(None, Some("env-token".to_string()), file).unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                url: "https://file.jetbrains.space".to_string(),
+                token: "env-token".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_missing_token() {
+        let result = Config::merge(
+            Some("https://example.jetbrains.space".to_string()),
+            None,
+            ConfigFile::default(),
+        );
+
+        assert!(matches!(result, Err(CliError::Config(_))));
+    }
+}
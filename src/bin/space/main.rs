@@ -0,0 +1,288 @@
+mod config;
+mod output;
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use space::{
+    ChannelIdentifier, ChatMessage, Client, Issue, IssueStatus, IssueUpdate, IssuesQuery, Message,
+    MessagesQuery, NewIssue, Project,
+};
+
+use config::Config;
+use output::{OutputFormat, write_json, write_rows};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    #[error(transparent)]
+    Space(#[from] space::Error),
+    #[error("{0}")]
+    Config(String),
+    #[error("{0}")]
+    NotFound(String),
+    #[error("Output error: {0}")]
+    Output(#[from] io::Error),
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+}
+
+#[derive(Parser)]
+#[command(
+    name = "space",
+    version,
+    about = "Command-line client for JetBrains Space"
+)]
+struct Cli {
+    #[arg(long, env = "SPACE_URL", global = true)]
+    url: Option<String>,
+    #[arg(long, env = "SPACE_TOKEN", global = true, hide_env_values = true)]
+    token: Option<String>,
+    #[arg(long, env = "SPACE_CONFIG", global = true)]
+    config: Option<PathBuf>,
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table, global = true)]
+    output: OutputFormat,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List projects
+    Projects,
+    /// List issues of a project
+    Issues {
+        /// Project key or id
+        project: String,
+        #[arg(long)]
+        status: Option<String>,
+        /// Assignee profile id
+        #[arg(long)]
+        assignee: Option<String>,
+        #[arg(long)]
+        search: Option<String>,
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+    },
+    /// Show an issue with its messages, CSV output contains only the messages
+    Issue { project: String, number: u32 },
+    /// Create an issue
+    Create {
+        project: String,
+        #[arg(long)]
+        title: String,
+        #[arg(long)]
+        description: Option<String>,
+        /// Status name, the first unresolved status by default
+        #[arg(long)]
+        status: Option<String>,
+        #[arg(long)]
+        assignee: Option<String>,
+    },
+    /// Update an issue
+    Update {
+        project: String,
+        number: u32,
+        #[arg(long)]
+        title: Option<String>,
+        #[arg(long)]
+        description: Option<String>,
+        #[arg(long)]
+        status: Option<String>,
+        #[arg(long)]
+        assignee: Option<String>,
+    },
+    /// Post a comment to an issue
+    Comment {
+        project: String,
+        number: u32,
+        text: String,
+    },
+}
+
+#[derive(Serialize)]
+struct IssueWithMessages<'a> {
+    issue: &'a Issue,
+    messages: &'a [Message],
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), CliError> {
+    let config = Config::load(cli.url, cli.token, cli.config.as_deref())?;
+    let client = Client::new(&config.url, &config.token, None);
+    let format = cli.output;
+    let mut out = io::stdout().lock();
+
+    match cli.command {
+        Command::Projects => {
+            let projects = client.get_projects().await?;
+            write_rows(&mut out, format, &projects)
+        }
+        Command::Issues {
+            project,
+            status,
+            assignee,
+            search,
+            limit,
+        } => {
+            let project = resolve_project(&client, &project).await?;
+            let mut query = IssuesQuery::new(&project.id);
+            query.top = limit;
+            query.assignee_id = assignee;
+            query.text = search;
+            if let Some(status) = status {
+                query.status_id = Some(resolve_status(&client, &project, Some(&status)).await?.id);
+            }
+
+            let issues = client.get_issues_for_project(query).await?;
+            write_rows(&mut out, format, &issues)
+        }
+        Command::Issue { project, number } => {
+            let project = resolve_project(&client, &project).await?;
+            let issue = client
+                .get_issue_for_project_by_number(&project.id, number)
+                .await?;
+            let messages = client
+                .get_issue_messages(MessagesQuery::new(&issue.id))
+                .await?;
+
+            match format {
+                OutputFormat::Json => write_json(
+                    &mut out,
+                    &IssueWithMessages {
+                        issue: &issue,
+                        messages: &messages,
+                    },
+                ),
+                // A single CSV stream can only hold one table.
+                OutputFormat::Csv => write_rows(&mut out, format, &messages),
+                OutputFormat::Table => {
+                    write_rows(&mut out, format, std::slice::from_ref(&issue))?;
+                    if let Some(description) = &issue.description {
+                        writeln!(out, "\n{description}")?;
+                    }
+                    writeln!(out)?;
+                    write_rows(&mut out, format, &messages)
+                }
+            }
+        }
+        Command::Create {
+            project,
+            title,
+            description,
+            status,
+            assignee,
+        } => {
+            let project = resolve_project(&client, &project).await?;
+            let status = resolve_status(&client, &project, status.as_deref()).await?;
+            let mut issue = NewIssue::new(&project.id, &title, &status.id);
+            issue.description = description;
+            issue.assignee_id = assignee;
+
+            let issue = client.create_issue(issue).await?;
+            write_rows(&mut out, format, &[issue])
+        }
+        Command::Update {
+            project,
+            number,
+            title,
+            description,
+            status,
+            assignee,
+        } => {
+            let project = resolve_project(&client, &project).await?;
+            let issue = client
+                .get_issue_for_project_by_number(&project.id, number)
+                .await?;
+            let status_id = match status {
+                Some(status) => Some(resolve_status(&client, &project, Some(&status)).await?.id),
+                None => None,
+            };
+
+            client
+                .update_issue(
+                    &project.id,
+                    &issue.id,
+                    IssueUpdate {
+                        title,
+                        description,
+                        status_id,
+                        assignee_id: assignee,
+                    },
+                )
+                .await?;
+
+            let issue = client
+                .get_issue_for_project_by_number(&project.id, number)
+                .await?;
+            write_rows(&mut out, format, &[issue])
+        }
+        Command::Comment {
+            project,
+            number,
+            text,
+        } => {
+            let project = resolve_project(&client, &project).await?;
+            let issue = client
+                .get_issue_for_project_by_number(&project.id, number)
+                .await?;
+
+            let message = client
+                .send_message(ChannelIdentifier::Issue(issue.id), ChatMessage::text(&text))
+                .await?;
+            write_rows(&mut out, format, &[message])
+        }
+    }
+}
+
+// Projects are usually referred to by key on the command line, ids are accepted as well.
+async fn resolve_project(client: &Client, project: &str) -> Result<Project, CliError> {
+    client
+        .get_projects()
+        .await?
+        .into_iter()
+        .find(|candidate| candidate.key.eq_ignore_ascii_case(project) || candidate.id == project)
+        .ok_or_else(|| CliError::NotFound(format!("project {project} not found")))
+}
+
+async fn resolve_status(
+    client: &Client,
+    project: &Project,
+    name: Option<&str>,
+) -> Result<IssueStatus, CliError> {
+    let statuses = client.get_issue_statuses(&project.id).await?;
+    let names = statuses
+        .iter()
+        .map(|status| status.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    statuses
+        .iter()
+        .find(|status| match name {
+            Some(name) => status.name.eq_ignore_ascii_case(name),
+            None => !status.resolved && !status.archived,
+        })
+        .cloned()
+        .ok_or_else(|| match name {
+            Some(name) => CliError::NotFound(format!(
+                "status {name} not found in {}, available statuses: {names}",
+                project.key
+            )),
+            None => CliError::NotFound(format!("{} has no open statuses", project.key)),
+        })
+}
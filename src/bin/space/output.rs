@@ -0,0 +1,162 @@
+use std::io::Write;
+
+use clap::ValueEnum;
+use serde::Serialize;
+use space::{Issue, Message, Project};
+
+use crate::CliError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+pub trait Row {
+    const HEADERS: &'static [&'static str];
+
+    fn cells(&self) -> Vec<String>;
+}
+
+impl Row for Project {
+    const HEADERS: &'static [&'static str] = &["ID", "KEY", "NAME"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![self.id.clone(), self.key.clone(), self.name.clone()]
+    }
+}
+
+impl Row for Issue {
+    const HEADERS: &'static [&'static str] = &["NUMBER", "STATUS", "ASSIGNEE", "TITLE", "CREATED"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.number.to_string(),
+            self.status.clone(),
+            self.assignee
+                .as_ref()
+                .map_or_else(|| "-".to_string(), |assignee| assignee.username.clone()),
+            self.title.clone(),
+            self.created_at.format("%Y-%m-%d %H:%M").to_string(),
+        ]
+    }
+}
+
+impl Row for Message {
+    const HEADERS: &'static [&'static str] = &["CREATED", "AUTHOR", "TEXT"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.created_at.format("%Y-%m-%d %H:%M").to_string(),
+            self.author.username.clone(),
+            self.text.clone(),
+        ]
+    }
+}
+
+pub fn write_rows<T: Row + Serialize>(
+    out: &mut impl Write,
+    format: OutputFormat,
+    rows: &[T],
+) -> Result<(), CliError> {
+    match format {
+        OutputFormat::Table => write_table(out, T::HEADERS, rows),
+        OutputFormat::Json => write_json(out, rows),
+        OutputFormat::Csv => write_csv(out, T::HEADERS, rows),
+    }
+}
+
+pub fn write_json(out: &mut impl Write, value: &(impl Serialize + ?Sized)) -> Result<(), CliError> {
+    serde_json::to_writer_pretty(&mut *out, value)
+        .map_err(|error| CliError::Output(error.into()))?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn write_csv<T: Row>(out: &mut impl Write, headers: &[&str], rows: &[T]) -> Result<(), CliError> {
+    let mut writer = csv::Writer::from_writer(out);
+    writer.write_record(headers)?;
+    for row in rows {
+        writer.write_record(row.cells())?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+// Multi-line cells are folded into one line so that every row stays on a single line.
+fn write_table<T: Row>(out: &mut impl Write, headers: &[&str], rows: &[T]) -> Result<(), CliError> {
+    let rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            row.cells()
+                .into_iter()
+                .map(|cell| cell.split_whitespace().collect::<Vec<_>>().join(" "))
+                .collect()
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = headers
+        .iter()
+        .map(|header| header.chars().count())
+        .collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let headers = headers.iter().map(ToString::to_string).collect::<Vec<_>>();
+    for row in std::iter::once(&headers).chain(&rows) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(out, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Pair(&'static str, &'static str);
+
+    impl Row for Pair {
+        const HEADERS: &'static [&'static str] = &["KEY", "VALUE"];
+
+        fn cells(&self) -> Vec<String> {
+            vec![self.0.to_string(), self.1.to_string()]
+        }
+    }
+
+    fn render(format: OutputFormat, rows: &[Pair]) -> String {
+        let mut out = Vec::new();
+        write_rows(&mut out, format, rows).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_table_output() {
+        let rows = [Pair("SPACE", "Space\nplatform"), Pair("X", "y")];
+
+        assert_eq!(
+            render(OutputFormat::Table, &rows),
+            "KEY    VALUE\nSPACE  Space platform\nX      y\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_output() {
+        let rows = [Pair("SPACE", "a, b")];
+
+        assert_eq!(
+            render(OutputFormat::Csv, &rows),
+            "KEY,VALUE\nSPACE,\"a, b\"\n"
+        );
+    }
+}
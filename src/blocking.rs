@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+#[cfg(feature = "export")]
+use crate::RecordWriter;
+use crate::{
+    Absence, AbsenceReason, AbsencesQuery, Application, ApplicationIdentifier, Article,
+    ArticleUpdate, ArticlesQuery, Attachment, BackupSummary, Branch, Channel, ChannelIdentifier,
+    ChannelsQuery, ChatMessage, Client, CodeReview, CodeReviewParticipant, CodeReviewsQuery,
+    Commit, CommitsQuery, CustomField, Document, DocumentFolder, DocumentSpace, DocumentUpdate,
+    Error, FileChange, FileDiff, GrantedRight, Issue, IssueChanges, IssueStatus, IssueSyncCursor,
+    IssueTag, IssueUpdate, IssuesQuery, Job, JobExecution, JobsQuery, Meeting, MeetingUpdate,
+    MeetingsQuery, Member, Membership, MembershipsQuery, MergeOptions, MergeResult, MergeStatus,
+    Message, MessagesBatch, MessagesQuery, NewAbsence, NewArticle, NewChannel, NewCustomField,
+    NewDocument, NewIssue, NewMeeting, NewMergeRequest, NewProject, NewSubscription, NewTodoItem,
+    NewWebhook, Package, PackageRepository, PackageVersion, PackageVersionDetails,
+    PermissionContext, Profile, Project, Repository, RestoreOptions, RestoreSummary, Right,
+    StepExecution, Subscription, SubscriptionUpdate, Team, TeamNode, TeamsQuery, TodoItem,
+    TodoItemUpdate, TodoItemsQuery, Webhook, WebhookUpdate, WorkingDays, WorkingWindow,
+};
+
+// Wraps the async client and drives every call on a private current-thread runtime, the same
+// way `reqwest::blocking` does. Calling it from inside another Tokio runtime panics.
+pub struct BlockingClient {
+    client: Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl BlockingClient {
+    pub fn new(
+        base_url: &str,
+        auth_token: &str,
+        http_client: Option<reqwest::Client>,
+    ) -> Result<Self, Error> {
+        Self::try_from(Client::new(base_url, auth_token, http_client))
+    }
+}
+
+impl TryFrom<Client> for BlockingClient {
+    type Error = Error;
+
+    fn try_from(client: Client) -> Result<Self, Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(Error::Runtime)?;
+
+        Ok(Self { client, runtime })
+    }
+}
+
+macro_rules! blocking_methods {
+    ($(fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $output:ty;)*) => {
+        impl BlockingClient {
+            $(
+                pub fn $name(&self $(, $arg: $ty)*) -> Result<$output, Error> {
+                    self.runtime.block_on(self.client.$name($($arg),*))
+                }
+            )*
+        }
+    };
+}
+
+blocking_methods! {
+    fn get_projects(&self) -> Vec<Project>;
+    fn create_project(&self, project: NewProject) -> Project;
+    fn get_issues_for_project(&self, query: IssuesQuery) -> Vec<Issue>;
+    fn get_issue_for_project_by_number(&self, project_id: &str, number: u32) -> Issue;
+    fn sync_issues(&self, project_id: &str, cursor: &IssueSyncCursor) -> IssueChanges;
+    fn get_issue_messages(&self, query: MessagesQuery) -> Vec<Message>;
+    fn get_channel_messages(&self, query: MessagesQuery) -> Vec<Message>;
+    fn get_channel_messages_batch(&self, query: MessagesQuery) -> MessagesBatch;
+    fn get_absences(&self, query: AbsencesQuery) -> Vec<Absence>;
+    fn get_member_absences(&self, member_id: &str, since: NaiveDate, till: NaiveDate) -> Vec<Absence>;
+    fn get_team_absences(&self, team_id: &str, since: NaiveDate, till: NaiveDate) -> Vec<Absence>;
+    fn get_absence_reasons(&self) -> Vec<AbsenceReason>;
+    fn create_absence(&self, absence: NewAbsence) -> Absence;
+    fn approve_absence(&self, absence_id: &str) -> ();
+    fn reject_absence(&self, absence_id: &str) -> ();
+    fn delete_absence(&self, absence_id: &str) -> ();
+    fn is_member_available(&self, member: &Member, date: NaiveDate) -> bool;
+    fn get_application(&self, application: &ApplicationIdentifier) -> Application;
+    fn get_current_application(&self) -> Application;
+    fn get_authorized_rights(&self, application: &ApplicationIdentifier, context: &PermissionContext) -> Vec<GrantedRight>;
+    fn request_rights(&self, application: &ApplicationIdentifier, context: &PermissionContext, rights: &[Right]) -> ();
+    fn approve_rights(&self, application: &ApplicationIdentifier, context: &PermissionContext, rights: &[Right]) -> ();
+    fn reject_rights(&self, application: &ApplicationIdentifier, context: &PermissionContext, rights: &[Right]) -> ();
+    fn remove_authorization(&self, application: &ApplicationIdentifier, context: &PermissionContext) -> ();
+    fn download_attachment(&self, attachment_id: &str) -> Vec<u8>;
+    fn upload_attachment(&self, filename: &str, content: Vec<u8>) -> Attachment;
+    fn add_issue_attachments(&self, project_id: &str, issue_id: &str, attachments: &[Attachment]) -> ();
+    fn get_jobs(&self, query: JobsQuery) -> Vec<Job>;
+    fn get_repository_jobs(&self, project_id: &str, repository: &str) -> Vec<Job>;
+    fn start_job(&self, project_id: &str, job_id: &str, branch: &str, parameters: &HashMap<String, String>) -> String;
+    fn get_job_execution(&self, execution_id: &str) -> JobExecution;
+    fn get_execution_steps(&self, execution_id: &str) -> Vec<StepExecution>;
+    fn get_step_log(&self, step_execution_id: &str) -> Vec<String>;
+    fn cancel_job_execution(&self, execution_id: &str) -> ();
+    fn backup(&self, directory: &Path) -> BackupSummary;
+    fn restore_backup(&self, directory: &Path, options: RestoreOptions) -> RestoreSummary;
+    fn get_articles(&self, query: ArticlesQuery) -> Vec<Article>;
+    fn get_article(&self, article_id: &str) -> Article;
+    fn create_article(&self, article: NewArticle) -> Article;
+    fn update_article(&self, article_id: &str, update: ArticleUpdate) -> Article;
+    fn get_channels(&self, query: ChannelsQuery) -> Vec<Channel>;
+    fn create_channel(&self, channel: NewChannel) -> Channel;
+    fn archive_channel(&self, channel: &ChannelIdentifier) -> ();
+    fn restore_channel(&self, channel: &ChannelIdentifier) -> ();
+    fn get_channel_members(&self, channel: &ChannelIdentifier) -> Vec<Member>;
+    fn add_channel_members(&self, channel: &ChannelIdentifier, profile_ids: &[&str]) -> ();
+    fn remove_channel_members(&self, channel: &ChannelIdentifier, profile_ids: &[&str]) -> ();
+    fn get_code_reviews(&self, query: CodeReviewsQuery) -> Vec<CodeReview>;
+    fn get_code_review(&self, project_id: &str, review_id: &str) -> CodeReview;
+    fn get_code_review_participants(&self, project_id: &str, review_id: &str) -> Vec<CodeReviewParticipant>;
+    fn get_code_review_messages(&self, review_id: &str) -> Vec<Message>;
+    fn get_code_review_commits(&self, project_id: &str, review_id: &str) -> Vec<Commit>;
+    fn get_code_review_changes(&self, project_id: &str, review_id: &str) -> Vec<FileChange>;
+    fn get_issue_custom_fields(&self, project_id: &str) -> Vec<CustomField>;
+    fn create_issue_custom_field(&self, project_id: &str, field: NewCustomField) -> CustomField;
+    fn get_document_folders(&self, space: &DocumentSpace, parent_folder_id: Option<&str>) -> Vec<DocumentFolder>;
+    fn get_folder_documents(&self, space: &DocumentSpace, folder_id: Option<&str>) -> Vec<Document>;
+    fn get_document(&self, space: &DocumentSpace, document_id: &str) -> Document;
+    fn create_document(&self, space: &DocumentSpace, document: NewDocument) -> Document;
+    fn update_document(&self, space: &DocumentSpace, document_id: &str, update: DocumentUpdate) -> Document;
+    fn move_document(&self, space: &DocumentSpace, document_id: &str, folder_id: Option<&str>) -> ();
+    fn get_project_repositories(&self, project_id: &str) -> Vec<Repository>;
+    fn get_branches(&self, project_id: &str, repository: &str) -> Vec<Branch>;
+    fn get_commits(&self, query: CommitsQuery) -> Vec<Commit>;
+    fn get_commit(&self, project_id: &str, repository: &str, revision: &str) -> Commit;
+    fn get_commit_changes(&self, project_id: &str, repository: &str, revision: &str) -> Vec<FileChange>;
+    fn get_file_diff(&self, project_id: &str, repository: &str, base_revision: &str, target_revision: &str, path: &str) -> FileDiff;
+    fn get_commit_issues(&self, project: &Project, commit: &Commit) -> Vec<Issue>;
+    fn get_issue_statuses(&self, project_id: &str) -> Vec<IssueStatus>;
+    fn get_issue_tags(&self, project_id: &str) -> Vec<IssueTag>;
+    fn create_issue_tag(&self, project_id: &str, name: &str, parent_id: Option<&str>) -> IssueTag;
+    fn create_issue(&self, issue: NewIssue) -> Issue;
+    fn update_issue(&self, project_id: &str, issue_id: &str, update: IssueUpdate) -> ();
+    fn get_meetings(&self, query: MeetingsQuery) -> Vec<Meeting>;
+    fn get_profile_meetings(&self, profile_id: &str, starting_after: DateTime<Utc>, ending_before: DateTime<Utc>) -> Vec<Meeting>;
+    fn create_meeting(&self, meeting: NewMeeting) -> Meeting;
+    fn update_meeting(&self, meeting_id: &str, update: MeetingUpdate) -> Meeting;
+    fn cancel_meeting(&self, meeting_id: &str) -> ();
+    fn create_merge_request(&self, merge_request: NewMergeRequest) -> CodeReview;
+    fn add_merge_request_reviewers(&self, project_id: &str, review_id: &str, profile_ids: &[&str]) -> ();
+    fn get_merge_status(&self, project_id: &str, review_id: &str) -> MergeStatus;
+    fn merge_merge_request(&self, project_id: &str, review_id: &str, options: MergeOptions) -> MergeResult;
+    fn send_message(&self, channel: ChannelIdentifier, content: impl Into<ChatMessage>) -> Message;
+    fn send_direct_message(&self, profile_id: &str, content: impl Into<ChatMessage>) -> Message;
+    fn get_package_repositories(&self, project_id: &str) -> Vec<PackageRepository>;
+    fn get_packages(&self, project_id: &str, repository_id: &str) -> Vec<Package>;
+    fn get_package_versions(&self, project_id: &str, repository_id: &str, package_name: &str) -> Vec<PackageVersion>;
+    fn get_package_version(&self, project_id: &str, repository_id: &str, package_name: &str, version: &str) -> PackageVersionDetails;
+    fn delete_package_version(&self, project_id: &str, repository_id: &str, package_name: &str, version: &str) -> ();
+    fn get_profile(&self, profile_id: &str) -> Profile;
+    fn get_profile_working_days(&self, profile_id: &str) -> Vec<WorkingDays>;
+    fn get_overlapping_working_hours(&self, members: &[Member], date: NaiveDate) -> Vec<WorkingWindow>;
+    fn get_teams(&self, query: TeamsQuery) -> Vec<Team>;
+    fn get_team_tree(&self, query: TeamsQuery) -> Vec<TeamNode>;
+    fn get_memberships(&self, query: MembershipsQuery) -> Vec<Membership>;
+    fn get_team_members(&self, team_id: &str) -> Vec<Membership>;
+    fn get_team_managers(&self, team_id: &str) -> Vec<Membership>;
+    fn get_profile_memberships(&self, profile_id: &str) -> Vec<Membership>;
+    fn get_todo_items(&self, query: TodoItemsQuery) -> Vec<TodoItem>;
+    fn create_todo_item(&self, item: NewTodoItem) -> TodoItem;
+    fn update_todo_item(&self, item_id: &str, update: TodoItemUpdate) -> ();
+    fn complete_todo_item(&self, item_id: &str) -> ();
+    fn delete_todo_item(&self, item_id: &str) -> ();
+    fn create_webhook(&self, application: &ApplicationIdentifier, webhook: NewWebhook) -> Webhook;
+    fn get_webhooks(&self, application: &ApplicationIdentifier) -> Vec<Webhook>;
+    fn update_webhook(&self, application: &ApplicationIdentifier, webhook_id: &str, update: WebhookUpdate) -> ();
+    fn delete_webhook(&self, application: &ApplicationIdentifier, webhook_id: &str) -> ();
+    fn create_subscription(&self, application: &ApplicationIdentifier, webhook_id: &str, subscription: NewSubscription) -> Subscription;
+    fn get_subscriptions(&self, application: &ApplicationIdentifier, webhook_id: &str) -> Vec<Subscription>;
+    fn update_subscription(&self, application: &ApplicationIdentifier, webhook_id: &str, subscription_id: &str, update: SubscriptionUpdate) -> ();
+    fn delete_subscription(&self, application: &ApplicationIdentifier, webhook_id: &str, subscription_id: &str) -> ();
+    fn get_space_public_keys(&self) -> String;
+}
+
+#[cfg(feature = "export")]
+blocking_methods! {
+    fn export_issues(&self, query: IssuesQuery, writer: &mut impl RecordWriter<Issue>) -> usize;
+    fn export_issue_messages(&self, issue: &Issue, writer: &mut impl RecordWriter<Message>) -> usize;
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, Member, deserializers, serializers};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Article {
+    pub id: String,
+    pub title: String,
+    pub content: String,
+    #[serde(default)]
+    pub author: Option<Member>,
+    #[serde(
+        rename(deserialize = "created"),
+        deserialize_with = "deserializers::deserialize_space_date"
+    )]
+    pub created_at: DateTime<Utc>,
+    #[serde(
+        rename(deserialize = "publicationDate"),
+        deserialize_with = "deserializers::deserialize_optional_space_date",
+        default
+    )]
+    pub published_at: Option<DateTime<Utc>>,
+    #[serde(
+        rename(deserialize = "teams"),
+        deserialize_with = "deserializers::deserialize_ids",
+        default
+    )]
+    pub team_ids: Vec<String>,
+    #[serde(
+        rename(deserialize = "locations"),
+        deserialize_with = "deserializers::deserialize_ids",
+        default
+    )]
+    pub location_ids: Vec<String>,
+    #[serde(
+        rename(deserialize = "projects"),
+        deserialize_with = "deserializers::deserialize_ids",
+        default
+    )]
+    pub project_ids: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ArticlesApiResponse {
+    data: Vec<Article>,
+}
+
+const ARTICLE_FIELDS: &str = "id,title,content,author(id,username),created,publicationDate,teams(id),locations(id),projects(id)";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ArticlesQuery {
+    pub term: Option<String>,
+    #[serde(rename(serialize = "team"))]
+    pub team_id: Option<String>,
+    #[serde(rename(serialize = "location"))]
+    pub location_id: Option<String>,
+    #[serde(rename(serialize = "project"))]
+    pub project_id: Option<String>,
+    #[serde(rename(serialize = "$top"))]
+    pub top: usize,
+    #[serde(rename(serialize = "$skip"))]
+    pub skip: usize,
+    #[serde(rename(serialize = "$fields"))]
+    pub fields: String,
+}
+
+impl ArticlesQuery {
+    pub fn new() -> Self {
+        Self {
+            term: None,
+            team_id: None,
+            location_id: None,
+            project_id: None,
+            top: 100000,
+            skip: 0,
+            fields: format!("data({ARTICLE_FIELDS}),next,totalCount"),
+        }
+    }
+}
+
+impl Default for ArticlesQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewArticle {
+    pub title: String,
+    pub content: String,
+    #[serde(
+        rename(serialize = "publicationDate"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub publish_at: Option<DateTime<Utc>>,
+    #[serde(
+        rename(serialize = "teams"),
+        serialize_with = "serializers::serialize_identifiers"
+    )]
+    pub team_ids: Vec<String>,
+    #[serde(
+        rename(serialize = "locations"),
+        serialize_with = "serializers::serialize_identifiers"
+    )]
+    pub location_ids: Vec<String>,
+    #[serde(
+        rename(serialize = "projects"),
+        serialize_with = "serializers::serialize_identifiers"
+    )]
+    pub project_ids: Vec<String>,
+}
+
+impl NewArticle {
+    pub fn new(title: &str, content: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            content: content.to_string(),
+            publish_at: None,
+            team_ids: vec![],
+            location_ids: vec![],
+            project_ids: vec![],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ArticleUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(
+        rename(serialize = "teams"),
+        serialize_with = "serializers::serialize_optional_identifiers",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub team_ids: Option<Vec<String>>,
+    #[serde(
+        rename(serialize = "locations"),
+        serialize_with = "serializers::serialize_optional_identifiers",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub location_ids: Option<Vec<String>>,
+    #[serde(
+        rename(serialize = "projects"),
+        serialize_with = "serializers::serialize_optional_identifiers",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub project_ids: Option<Vec<String>>,
+}
+
+impl Client {
+    pub async fn get_articles(&self, query: ArticlesQuery) -> Result<Vec<Article>, Error> {
+        let url = format!("{}/api/http/blog", self.base_url);
+
+        Ok(self
+            .send_request::<_, ArticlesApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_article(&self, article_id: &str) -> Result<Article, Error> {
+        let url = format!("{}/api/http/blog/id:{article_id}", self.base_url);
+
+        let query = HashMap::from([("$fields", ARTICLE_FIELDS)]);
+
+        self.send_request::<_, Article>(&url, query).await
+    }
+
+    pub async fn create_article(&self, article: NewArticle) -> Result<Article, Error> {
+        let url = format!("{}/api/http/blog", self.base_url);
+
+        self.send_json_request::<_, Article>(reqwest::Method::POST, &url, article)
+            .await
+    }
+
+    pub async fn update_article(
+        &self,
+        article_id: &str,
+        update: ArticleUpdate,
+    ) -> Result<Article, Error> {
+        let url = format!("{}/api/http/blog/id:{article_id}", self.base_url);
+
+        self.send_json_request::<_, Article>(reqwest::Method::PATCH, &url, update)
+            .await
+    }
+}
@@ -0,0 +1,306 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, Member, encode_path_segment};
+
+type ChannelIdentifierConstructor = fn(String) -> ChannelIdentifier;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelIdentifier {
+    Issue(String),
+    Channel(String),
+    Profile(String),
+    CodeReview(String),
+    Document(String),
+    Id(String),
+}
+
+impl fmt::Display for ChannelIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Issue(id) => write!(f, "issue:id:{id}"),
+            Self::Channel(name) => write!(f, "channel:name:{name}"),
+            Self::Profile(id) => write!(f, "member:id:{id}"),
+            Self::CodeReview(id) => write!(f, "codeReview:id:{id}"),
+            Self::Document(id) => write!(f, "document:id:{id}"),
+            Self::Id(id) => write!(f, "id:{id}"),
+        }
+    }
+}
+
+impl FromStr for ChannelIdentifier {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let prefixes: [(&str, ChannelIdentifierConstructor); 6] = [
+            ("issue:id:", Self::Issue),
+            ("channel:name:", Self::Channel),
+            ("member:id:", Self::Profile),
+            ("codeReview:id:", Self::CodeReview),
+            ("document:id:", Self::Document),
+            ("id:", Self::Id),
+        ];
+
+        prefixes
+            .into_iter()
+            .find_map(|(prefix, constructor)| {
+                value
+                    .strip_prefix(prefix)
+                    .filter(|rest| !rest.is_empty())
+                    .map(|rest| constructor(rest.to_string()))
+            })
+            .ok_or_else(|| Error::InvalidChannelIdentifier(value.to_string()))
+    }
+}
+
+impl Serialize for ChannelIdentifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChannelIdentifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Channel {
+    #[serde(alias = "channelId")]
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub private: bool,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+impl Channel {
+    pub fn identifier(&self) -> ChannelIdentifier {
+        ChannelIdentifier::Id(self.id.clone())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ChannelsApiResponse {
+    data: Vec<Channel>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ChannelMembersApiResponse {
+    data: Vec<Member>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChannelsQuery {
+    pub query: Option<String>,
+    #[serde(rename(serialize = "withArchived"))]
+    pub with_archived: bool,
+    #[serde(rename(serialize = "$top"))]
+    pub top: usize,
+    #[serde(rename(serialize = "$skip"))]
+    pub skip: usize,
+    #[serde(rename(serialize = "$fields"))]
+    pub fields: String,
+}
+
+impl ChannelsQuery {
+    pub fn new() -> Self {
+        Self {
+            query: None,
+            with_archived: false,
+            top: 100000,
+            skip: 0,
+            fields: "data(channelId,name,description,private,archived),next,totalCount".to_string(),
+        }
+    }
+}
+
+impl Default for ChannelsQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewChannel {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub private: bool,
+}
+
+impl NewChannel {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            description: None,
+            private: false,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ChannelMembersRequest {
+    profiles: Vec<String>,
+}
+
+impl ChannelMembersRequest {
+    fn new(profile_ids: &[&str]) -> Self {
+        Self {
+            profiles: profile_ids.iter().map(|id| format!("id:{id}")).collect(),
+        }
+    }
+}
+
+impl Client {
+    pub async fn get_channels(&self, query: ChannelsQuery) -> Result<Vec<Channel>, Error> {
+        let url = format!("{}/api/http/chats/channels/all-channels", self.base_url);
+
+        Ok(self
+            .send_request::<_, ChannelsApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn create_channel(&self, channel: NewChannel) -> Result<Channel, Error> {
+        let url = format!("{}/api/http/chats/channels", self.base_url);
+
+        self.send_json_request::<_, Channel>(reqwest::Method::POST, &url, channel)
+            .await
+    }
+
+    pub async fn archive_channel(&self, channel: &ChannelIdentifier) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/chats/channels/{}/archive",
+            self.base_url,
+            encode_path_segment(&channel.to_string())
+        );
+
+        self.send_request_without_response::<()>(reqwest::Method::POST, &url, None)
+            .await
+    }
+
+    pub async fn restore_channel(&self, channel: &ChannelIdentifier) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/chats/channels/{}/restore-archived",
+            self.base_url,
+            encode_path_segment(&channel.to_string())
+        );
+
+        self.send_request_without_response::<()>(reqwest::Method::POST, &url, None)
+            .await
+    }
+
+    pub async fn get_channel_members(
+        &self,
+        channel: &ChannelIdentifier,
+    ) -> Result<Vec<Member>, Error> {
+        let url = format!(
+            "{}/api/http/chats/channels/{}/subscribers/users",
+            self.base_url,
+            encode_path_segment(&channel.to_string())
+        );
+
+        let query = [("$fields", "data(id,username),next,totalCount")];
+
+        Ok(self
+            .send_request::<_, ChannelMembersApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn add_channel_members(
+        &self,
+        channel: &ChannelIdentifier,
+        profile_ids: &[&str],
+    ) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/chats/channels/{}/subscribers/users",
+            self.base_url,
+            encode_path_segment(&channel.to_string())
+        );
+
+        self.send_request_without_response(
+            reqwest::Method::PATCH,
+            &url,
+            Some(ChannelMembersRequest::new(profile_ids)),
+        )
+        .await
+    }
+
+    pub async fn remove_channel_members(
+        &self,
+        channel: &ChannelIdentifier,
+        profile_ids: &[&str],
+    ) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/chats/channels/{}/subscribers/users",
+            self.base_url,
+            encode_path_segment(&channel.to_string())
+        );
+
+        self.send_request_without_response(
+            reqwest::Method::DELETE,
+            &url,
+            Some(ChannelMembersRequest::new(profile_ids)),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_identifier_round_trip() {
+        let identifiers = [
+            ChannelIdentifier::Issue("0198bc7b".to_string()),
+            ChannelIdentifier::Channel("general".to_string()),
+            ChannelIdentifier::Profile("0198ad98".to_string()),
+            ChannelIdentifier::CodeReview("0198c4f1".to_string()),
+            ChannelIdentifier::Document("0198c5a2".to_string()),
+            ChannelIdentifier::Id("0198c6b3".to_string()),
+        ];
+
+        for identifier in identifiers {
+            let parsed: ChannelIdentifier = identifier.to_string().parse().unwrap();
+            assert_eq!(parsed, identifier);
+        }
+    }
+
+    #[test]
+    fn test_channel_identifier_formats() {
+        assert_eq!(
+            ChannelIdentifier::Issue("42".to_string()).to_string(),
+            "issue:id:42"
+        );
+        assert_eq!(
+            ChannelIdentifier::Channel("general".to_string()).to_string(),
+            "channel:name:general"
+        );
+        assert_eq!(
+            ChannelIdentifier::Profile("42".to_string()).to_string(),
+            "member:id:42"
+        );
+    }
+
+    #[test]
+    fn test_channel_identifier_invalid() {
+        assert!("unknown:42".parse::<ChannelIdentifier>().is_err());
+        assert!("issue:id:".parse::<ChannelIdentifier>().is_err());
+    }
+}
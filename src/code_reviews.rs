@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::git::FileChangesApiResponse;
+use crate::{
+    ChannelIdentifier, Client, Commit, Error, FileChange, Member, Message, MessagesQuery,
+    deserializers,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CodeReview {
+    pub id: String,
+    pub number: usize,
+    pub title: String,
+    pub state: CodeReviewState,
+    #[serde(
+        rename(deserialize = "createdAt"),
+        deserialize_with = "deserializers::deserialize_timestamp_millis"
+    )]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename(deserialize = "createdBy"), default)]
+    pub created_by: Option<Member>,
+    #[serde(default)]
+    pub participants: Vec<CodeReviewParticipant>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodeReviewState {
+    Opened,
+    Closed,
+    Deleted,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CodeReviewParticipant {
+    pub user: Member,
+    pub role: CodeReviewParticipantRole,
+    #[serde(default)]
+    pub state: Option<ReviewerState>,
+    #[serde(rename(deserialize = "theirTurn"), default)]
+    pub their_turn: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodeReviewParticipantRole {
+    Author,
+    Reviewer,
+    Watcher,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewerState {
+    Accepted,
+    Rejected,
+    Resumed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CodeReviewWithCount {
+    review: CodeReview,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CodeReviewsApiResponse {
+    data: Vec<CodeReviewWithCount>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ReviewCommit {
+    commit: Commit,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ReviewCommitsApiResponse {
+    data: Vec<ReviewCommit>,
+}
+
+const CODE_REVIEW_FIELDS: &str = "id,number,title,state,createdAt,createdBy(id,username),participants(user(id,username),role,state,theirTurn)";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CodeReviewsQuery {
+    #[serde(skip_serializing)]
+    pub project_id: String,
+    pub state: Option<CodeReviewState>,
+    pub repository: Option<String>,
+    #[serde(rename(serialize = "$top"))]
+    pub top: usize,
+    #[serde(rename(serialize = "$skip"))]
+    pub skip: usize,
+    #[serde(rename(serialize = "$fields"))]
+    pub fields: String,
+}
+
+impl CodeReviewsQuery {
+    pub fn new(project_id: &str) -> Self {
+        Self {
+            project_id: project_id.to_string(),
+            state: None,
+            repository: None,
+            top: 100000,
+            skip: 0,
+            fields: format!("data(review({CODE_REVIEW_FIELDS})),next,totalCount"),
+        }
+    }
+}
+
+impl Client {
+    pub async fn get_code_reviews(
+        &self,
+        query: CodeReviewsQuery,
+    ) -> Result<Vec<CodeReview>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{}/code-reviews",
+            self.base_url, query.project_id
+        );
+
+        Ok(self
+            .send_request::<_, CodeReviewsApiResponse>(&url, query)
+            .await?
+            .data
+            .into_iter()
+            .map(|entry| entry.review)
+            .collect())
+    }
+
+    pub async fn get_code_review(
+        &self,
+        project_id: &str,
+        review_id: &str,
+    ) -> Result<CodeReview, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/code-reviews/id:{review_id}",
+            self.base_url
+        );
+
+        let query = HashMap::from([("$fields", CODE_REVIEW_FIELDS)]);
+
+        self.send_request::<_, CodeReview>(&url, query).await
+    }
+
+    pub async fn get_code_review_participants(
+        &self,
+        project_id: &str,
+        review_id: &str,
+    ) -> Result<Vec<CodeReviewParticipant>, Error> {
+        Ok(self
+            .get_code_review(project_id, review_id)
+            .await?
+            .participants)
+    }
+
+    pub async fn get_code_review_messages(&self, review_id: &str) -> Result<Vec<Message>, Error> {
+        self.get_channel_messages(MessagesQuery::for_channel(ChannelIdentifier::CodeReview(
+            review_id.to_string(),
+        )))
+        .await
+    }
+
+    pub async fn get_code_review_commits(
+        &self,
+        project_id: &str,
+        review_id: &str,
+    ) -> Result<Vec<Commit>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/code-reviews/id:{review_id}/commits",
+            self.base_url
+        );
+
+        let query = HashMap::from([
+            (
+                "$fields",
+                "data(commit(id,message,author(name,email),authorDate,parents)),next,totalCount",
+            ),
+            ("$top", "100000"),
+        ]);
+
+        Ok(self
+            .send_request::<_, ReviewCommitsApiResponse>(&url, query)
+            .await?
+            .data
+            .into_iter()
+            .map(|entry| entry.commit)
+            .collect())
+    }
+
+    pub async fn get_code_review_changes(
+        &self,
+        project_id: &str,
+        review_id: &str,
+    ) -> Result<Vec<FileChange>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/code-reviews/id:{review_id}/changes",
+            self.base_url
+        );
+
+        let query = HashMap::from([
+            (
+                "$fields",
+                "data(changeType,old(path),new(path),revision),next,totalCount",
+            ),
+            ("$top", "100000"),
+        ]);
+
+        Ok(self
+            .send_request::<_, FileChangesApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+}
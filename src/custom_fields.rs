@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, Member};
+
+const CUSTOM_FIELD_FIELDS: &str = "id,name,type,archived,enumValues(id,value)";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(tag = "className")]
+pub enum CustomFieldType {
+    #[serde(rename = "StringCFType")]
+    String,
+    #[serde(rename = "IntCFType")]
+    Integer,
+    #[serde(rename = "BooleanCFType")]
+    Boolean,
+    #[serde(rename = "EnumCFType")]
+    Enum,
+    #[serde(rename = "ProfileCFType")]
+    Profile,
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EnumValue {
+    pub id: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CustomField {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: CustomFieldType,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(rename = "enumValues", default)]
+    pub enum_values: Vec<EnumValue>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "className")]
+pub enum CustomFieldValue {
+    #[serde(rename = "StringCFValue")]
+    String { value: Option<String> },
+    #[serde(rename = "IntCFValue")]
+    Integer { value: Option<i64> },
+    #[serde(rename = "BooleanCFValue")]
+    Boolean { value: Option<bool> },
+    #[serde(rename = "EnumCFValue")]
+    Enum { value: Option<EnumValue> },
+    #[serde(rename = "ProfileCFValue")]
+    Profile { profile: Option<Member> },
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "className")]
+pub enum CustomFieldInputValue {
+    #[serde(rename = "StringCFInputValue")]
+    String { value: Option<String> },
+    #[serde(rename = "IntCFInputValue")]
+    Integer { value: Option<i64> },
+    #[serde(rename = "BooleanCFInputValue")]
+    Boolean { value: Option<bool> },
+    #[serde(rename = "EnumCFInputValue")]
+    Enum {
+        #[serde(rename = "enumValueId")]
+        enum_value_id: Option<String>,
+    },
+    #[serde(rename = "ProfileCFInputValue")]
+    Profile {
+        #[serde(rename = "profile")]
+        profile_id: Option<String>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CustomFieldInput {
+    #[serde(rename = "fieldId")]
+    pub field_id: String,
+    pub value: CustomFieldInputValue,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewCustomField {
+    pub name: String,
+    #[serde(rename(serialize = "type"))]
+    pub field_type: CustomFieldType,
+    #[serde(
+        rename(serialize = "enumValues"),
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub enum_values: Vec<String>,
+}
+
+impl NewCustomField {
+    pub fn new(name: &str, field_type: CustomFieldType) -> Self {
+        Self {
+            name: name.to_string(),
+            field_type,
+            enum_values: vec![],
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct NewCustomFieldRequest<'a> {
+    scope: String,
+    #[serde(flatten)]
+    field: &'a NewCustomField,
+}
+
+impl Client {
+    // Issue fields are defined per project.
+    pub async fn get_issue_custom_fields(
+        &self,
+        project_id: &str,
+    ) -> Result<Vec<CustomField>, Error> {
+        let url = format!("{}/api/http/custom-fields-v2/Issue/fields", self.base_url);
+
+        let query = [
+            ("scope", format!("project:id:{project_id}")),
+            ("$fields", CUSTOM_FIELD_FIELDS.to_string()),
+        ];
+
+        self.send_request::<_, Vec<CustomField>>(&url, query).await
+    }
+
+    pub async fn create_issue_custom_field(
+        &self,
+        project_id: &str,
+        field: NewCustomField,
+    ) -> Result<CustomField, Error> {
+        let url = format!("{}/api/http/custom-fields-v2/Issue/fields", self.base_url);
+
+        let query = [("$fields", CUSTOM_FIELD_FIELDS)];
+        let body = NewCustomFieldRequest {
+            scope: format!("project:id:{project_id}"),
+            field: &field,
+        };
+
+        self.send_json_request_with_query::<_, _, CustomField>(
+            reqwest::Method::POST,
+            &url,
+            query,
+            body,
+        )
+        .await
+    }
+}
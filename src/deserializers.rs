@@ -1,5 +1,6 @@
-use crate::Member;
-use chrono::{DateTime, Utc};
+use crate::attachments::AttachmentDetails;
+use crate::{Attachment, Member, PackageType, WorkingDay};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use serde::Deserialize;
 
 pub fn deserialize_created_by<'de, D>(deserializer: D) -> Result<Member, D::Error>
@@ -88,6 +89,246 @@ where
     Ok(helper.key)
 }
 
+pub fn deserialize_optional_space_date<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct SpaceDate {
+        iso: DateTime<Utc>,
+    }
+
+    let helper = Option::<SpaceDate>::deserialize(deserializer)?;
+    Ok(helper.map(|date| date.iso))
+}
+
+pub fn deserialize_optional_id<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Id {
+        id: String,
+    }
+
+    let helper = Option::<Id>::deserialize(deserializer)?;
+    Ok(helper.map(|reference| reference.id))
+}
+
+pub fn deserialize_ids<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Id {
+        id: String,
+    }
+
+    let helper = Vec::<Id>::deserialize(deserializer)?;
+    Ok(helper.into_iter().map(|reference| reference.id).collect())
+}
+
+pub fn deserialize_attachments<'de, D>(deserializer: D) -> Result<Vec<Attachment>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct AttachmentInfo {
+        details: Option<AttachmentDetails>,
+    }
+
+    let helper = Vec::<AttachmentInfo>::deserialize(deserializer)?;
+    Ok(helper
+        .into_iter()
+        .filter_map(|info| info.details.and_then(AttachmentDetails::into_attachment))
+        .collect())
+}
+
+pub fn deserialize_package_type<'de, D>(deserializer: D) -> Result<PackageType, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Type {
+        id: PackageType,
+    }
+
+    let helper = Type::deserialize(deserializer)?;
+    Ok(helper.id)
+}
+
+pub fn deserialize_text_content<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Content {
+        text: String,
+    }
+
+    let helper = Content::deserialize(deserializer)?;
+    Ok(helper.text)
+}
+
+pub fn deserialize_endpoint_url<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Endpoint {
+        url: String,
+    }
+
+    let helper = Endpoint::deserialize(deserializer)?;
+    Ok(helper.url)
+}
+
+pub fn deserialize_identifier<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let identifier = String::deserialize(deserializer)?;
+    Ok(identifier
+        .strip_prefix("id:")
+        .map_or(identifier.clone(), str::to_string))
+}
+
+pub fn deserialize_optional_name<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Name {
+        name: String,
+    }
+
+    let helper = Option::<Name>::deserialize(deserializer)?;
+    Ok(helper.map(|named| named.name))
+}
+
+pub fn deserialize_space_local_date<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum SpaceLocalDate {
+        Plain(NaiveDate),
+        Wrapped { iso: NaiveDate },
+    }
+
+    Ok(match SpaceLocalDate::deserialize(deserializer)? {
+        SpaceLocalDate::Plain(date) | SpaceLocalDate::Wrapped { iso: date } => date,
+    })
+}
+
+pub fn deserialize_working_time<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct WorkingTime {
+        hours: u32,
+        minutes: u32,
+    }
+
+    let helper = WorkingTime::deserialize(deserializer)?;
+    // Space reports the end of the day as 24:00, which is midnight of the next day.
+    NaiveTime::from_hms_opt(helper.hours % 24, helper.minutes, 0).ok_or_else(|| {
+        serde::de::Error::custom(format!(
+            "invalid working time {}:{}",
+            helper.hours, helper.minutes
+        ))
+    })
+}
+
+pub fn deserialize_optional_space_local_date<'de, D>(
+    deserializer: D,
+) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct SpaceLocalDate(#[serde(deserialize_with = "deserialize_space_local_date")] NaiveDate);
+
+    let helper = Option::<SpaceLocalDate>::deserialize(deserializer)?;
+    Ok(helper.map(|date| date.0))
+}
+
+pub fn deserialize_working_days_spec<'de, D>(deserializer: D) -> Result<Vec<WorkingDay>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct WorkingDaysSpec {
+        days: Vec<WorkingDay>,
+    }
+
+    let helper = WorkingDaysSpec::deserialize(deserializer)?;
+    Ok(helper.days)
+}
+
+pub fn deserialize_timestamp_millis<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let timestamp = i64::deserialize(deserializer)?;
+    DateTime::from_timestamp_millis(timestamp)
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid timestamp {timestamp}")))
+}
+
+pub fn deserialize_optional_timestamp_millis<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<i64>::deserialize(deserializer)?
+        .map(|timestamp| {
+            DateTime::from_timestamp_millis(timestamp)
+                .ok_or_else(|| serde::de::Error::custom(format!("invalid timestamp {timestamp}")))
+        })
+        .transpose()
+}
+
+pub fn deserialize_optional_path<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct GitFile {
+        path: String,
+    }
+
+    let helper = Option::<GitFile>::deserialize(deserializer)?;
+    Ok(helper.map(|file| file.path))
+}
+
+pub fn deserialize_conflicting_paths<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct GitFile {
+        path: String,
+    }
+
+    let helper = Vec::<GitFile>::deserialize(deserializer)?;
+    Ok(helper.into_iter().map(|file| file.path).collect())
+}
+
+pub fn deserialize_branch_name<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let head = String::deserialize(deserializer)?;
+    Ok(head
+        .strip_prefix("refs/heads/")
+        .map_or(head.clone(), str::to_string))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +366,42 @@ mod tests {
         key: String,
     }
 
+    #[derive(Deserialize)]
+    struct TestOptionalSpaceDate {
+        #[serde(deserialize_with = "deserialize_optional_space_date", default)]
+        date: Option<DateTime<Utc>>,
+    }
+
+    #[derive(Deserialize)]
+    struct TestOptionalId {
+        #[serde(deserialize_with = "deserialize_optional_id", default)]
+        parent: Option<String>,
+    }
+
+    #[derive(Deserialize)]
+    struct TestOptionalName {
+        #[serde(deserialize_with = "deserialize_optional_name", default)]
+        role: Option<String>,
+    }
+
+    #[derive(Deserialize)]
+    struct TestSpaceLocalDate {
+        #[serde(deserialize_with = "deserialize_space_local_date")]
+        date: NaiveDate,
+    }
+
+    #[derive(Deserialize)]
+    struct TestWorkingTime {
+        #[serde(deserialize_with = "deserialize_working_time")]
+        time: NaiveTime,
+    }
+
+    #[derive(Deserialize)]
+    struct TestTimestampMillis {
+        #[serde(deserialize_with = "deserialize_timestamp_millis")]
+        date: DateTime<Utc>,
+    }
+
     #[test]
     fn test_deserialize_created_by_success() {
         let json_data = json!({
@@ -236,4 +513,88 @@ mod tests {
         assert_eq!(assignee.id, "0198ad98-74d8-7a6a-8212-c78297ee1c35");
         assert_eq!(assignee.username, "jane_smith");
     }
+
+    #[test]
+    fn test_deserialize_optional_space_date() {
+        let wrapper: TestOptionalSpaceDate = serde_json::from_value(json!({
+            "date": { "iso": "2030-10-25T10:30:00Z" }
+        }))
+        .unwrap();
+
+        let expected = DateTime::parse_from_rfc3339("2030-10-25T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(wrapper.date, Some(expected));
+
+        let wrapper: TestOptionalSpaceDate = serde_json::from_value(json!({})).unwrap();
+        assert!(wrapper.date.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_optional_id() {
+        let wrapper: TestOptionalId = serde_json::from_value(json!({
+            "parent": { "id": "0198ad98-74d8-7eba-80a2-65f2e3fc2a9d", "name": "Root" }
+        }))
+        .unwrap();
+        assert_eq!(
+            wrapper.parent,
+            Some("0198ad98-74d8-7eba-80a2-65f2e3fc2a9d".to_string())
+        );
+
+        let wrapper: TestOptionalId = serde_json::from_value(json!({ "parent": null })).unwrap();
+        assert!(wrapper.parent.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_optional_name() {
+        let wrapper: TestOptionalName = serde_json::from_value(json!({
+            "role": { "id": "1", "name": "Developer" }
+        }))
+        .unwrap();
+        assert_eq!(wrapper.role, Some("Developer".to_string()));
+
+        let wrapper: TestOptionalName = serde_json::from_value(json!({ "role": null })).unwrap();
+        assert!(wrapper.role.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_space_local_date() {
+        let expected = NaiveDate::from_ymd_opt(2030, 10, 25).unwrap();
+
+        let wrapper: TestSpaceLocalDate = serde_json::from_value(json!({
+            "date": { "iso": "2030-10-25", "year": 2030, "month": 10, "day": 25 }
+        }))
+        .unwrap();
+        assert_eq!(wrapper.date, expected);
+
+        let wrapper: TestSpaceLocalDate =
+            serde_json::from_value(json!({ "date": "2030-10-25" })).unwrap();
+        assert_eq!(wrapper.date, expected);
+    }
+
+    #[test]
+    fn test_deserialize_working_time() {
+        let wrapper: TestWorkingTime =
+            serde_json::from_value(json!({ "time": { "hours": 9, "minutes": 30 } })).unwrap();
+        assert_eq!(wrapper.time, NaiveTime::from_hms_opt(9, 30, 0).unwrap());
+
+        let wrapper: TestWorkingTime =
+            serde_json::from_value(json!({ "time": { "hours": 24, "minutes": 0 } })).unwrap();
+        assert_eq!(wrapper.time, NaiveTime::MIN);
+
+        let result: Result<TestWorkingTime, _> =
+            serde_json::from_value(json!({ "time": { "hours": 9, "minutes": 75 } }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_timestamp_millis() {
+        let wrapper: TestTimestampMillis =
+            serde_json::from_value(json!({ "date": 1748444974648_i64 })).unwrap();
+
+        let expected = DateTime::parse_from_rfc3339("2025-05-28T15:09:34.648Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(wrapper.date, expected);
+    }
 }
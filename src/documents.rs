@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, deserializers, serializers};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocumentSpace {
+    Project(String),
+    Personal(String),
+}
+
+impl DocumentSpace {
+    fn url(&self, base_url: &str) -> String {
+        match self {
+            Self::Project(id) => format!("{base_url}/api/http/projects/id:{id}/documents"),
+            Self::Personal(id) => {
+                format!("{base_url}/api/http/team-directory/profiles/id:{id}/documents")
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DocumentFolder {
+    pub id: String,
+    pub name: String,
+    #[serde(
+        rename(deserialize = "parentFolder"),
+        deserialize_with = "deserializers::deserialize_optional_id",
+        default
+    )]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Document {
+    pub id: String,
+    pub title: String,
+    #[serde(
+        rename(deserialize = "folder"),
+        deserialize_with = "deserializers::deserialize_optional_id",
+        default
+    )]
+    pub folder_id: Option<String>,
+    #[serde(rename(deserialize = "documentBody"), default)]
+    pub body: Option<DocumentBody>,
+}
+
+impl Document {
+    pub fn text(&self) -> Option<&str> {
+        match &self.body {
+            Some(DocumentBody::Text { text }) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+// Only markdown text documents are supported; checklists, whiteboards and files are
+// kept as `Unsupported` so that listing a mixed folder does not fail.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "className")]
+pub enum DocumentBody {
+    #[serde(rename = "TextDocumentHttpBody")]
+    Text { text: String },
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewDocument {
+    #[serde(rename(serialize = "name"))]
+    pub title: String,
+    #[serde(
+        rename(serialize = "folder"),
+        serialize_with = "serializers::serialize_folder_identifier"
+    )]
+    pub folder_id: Option<String>,
+    #[serde(
+        rename(serialize = "bodyIn"),
+        serialize_with = "serializers::serialize_text_body_create"
+    )]
+    pub text: String,
+}
+
+impl NewDocument {
+    pub fn new(title: &str, text: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            folder_id: None,
+            text: text.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DocumentUpdate {
+    #[serde(rename(serialize = "name"), skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(
+        rename(serialize = "updateIn"),
+        serialize_with = "serializers::serialize_text_body_update",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub text: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DocumentFoldersApiResponse {
+    data: Vec<DocumentFolder>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DocumentsApiResponse {
+    data: Vec<Document>,
+}
+
+#[derive(Serialize)]
+struct MoveDocumentRequest {
+    #[serde(serialize_with = "serializers::serialize_folder_identifier")]
+    folder: Option<String>,
+}
+
+const DOCUMENT_FIELDS: &str = "id,title,folder(id),documentBody(className,text)";
+
+impl Client {
+    pub async fn get_document_folders(
+        &self,
+        space: &DocumentSpace,
+        parent_folder_id: Option<&str>,
+    ) -> Result<Vec<DocumentFolder>, Error> {
+        let url = format!(
+            "{}/folders/{}/subfolders",
+            space.url(&self.base_url),
+            serializers::folder_identifier(parent_folder_id)
+        );
+
+        let query = HashMap::from([
+            ("$fields", "data(id,name,parentFolder(id)),next,totalCount"),
+            ("$top", "100000"),
+        ]);
+
+        Ok(self
+            .send_request::<_, DocumentFoldersApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_folder_documents(
+        &self,
+        space: &DocumentSpace,
+        folder_id: Option<&str>,
+    ) -> Result<Vec<Document>, Error> {
+        let url = format!(
+            "{}/folders/{}/documents",
+            space.url(&self.base_url),
+            serializers::folder_identifier(folder_id)
+        );
+
+        let query = HashMap::from([
+            ("$fields", "data(id,title,folder(id)),next,totalCount"),
+            ("$top", "100000"),
+        ]);
+
+        Ok(self
+            .send_request::<_, DocumentsApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_document(
+        &self,
+        space: &DocumentSpace,
+        document_id: &str,
+    ) -> Result<Document, Error> {
+        let url = format!("{}/id:{document_id}", space.url(&self.base_url));
+
+        let query = HashMap::from([("$fields", DOCUMENT_FIELDS)]);
+
+        self.send_request::<_, Document>(&url, query).await
+    }
+
+    pub async fn create_document(
+        &self,
+        space: &DocumentSpace,
+        document: NewDocument,
+    ) -> Result<Document, Error> {
+        let url = space.url(&self.base_url);
+
+        self.send_json_request::<_, Document>(reqwest::Method::POST, &url, document)
+            .await
+    }
+
+    pub async fn update_document(
+        &self,
+        space: &DocumentSpace,
+        document_id: &str,
+        update: DocumentUpdate,
+    ) -> Result<Document, Error> {
+        let url = format!("{}/id:{document_id}", space.url(&self.base_url));
+
+        self.send_json_request::<_, Document>(reqwest::Method::PATCH, &url, update)
+            .await
+    }
+
+    pub async fn move_document(
+        &self,
+        space: &DocumentSpace,
+        document_id: &str,
+        folder_id: Option<&str>,
+    ) -> Result<(), Error> {
+        let url = format!("{}/id:{document_id}/move", space.url(&self.base_url));
+
+        self.send_request_without_response(
+            reqwest::Method::POST,
+            &url,
+            Some(MoveDocumentRequest {
+                folder: folder_id.map(str::to_string),
+            }),
+        )
+        .await
+    }
+}
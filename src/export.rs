@@ -0,0 +1,353 @@
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde_json::{Map, Value};
+
+use crate::{Client, Error, Issue, IssuesQuery, Message, MessagesQuery, MessagesSorting};
+
+const DEFAULT_EXPORT_PAGE_SIZE: usize = 500;
+
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    pub time_zone: Tz,
+    pub date_format: String,
+}
+
+impl ExportOptions {
+    pub fn new() -> Self {
+        Self {
+            time_zone: Tz::UTC,
+            date_format: "%Y-%m-%dT%H:%M:%S%:z".to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn time_zone(mut self, time_zone: Tz) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    #[must_use]
+    pub fn date_format(mut self, date_format: &str) -> Self {
+        self.date_format = date_format.to_string();
+        self
+    }
+
+    // Formatting through `write!` reports an invalid format instead of panicking like `to_string`.
+    fn format_date(&self, date: DateTime<Utc>) -> Result<String, Error> {
+        let mut formatted = String::new();
+        write!(
+            formatted,
+            "{}",
+            date.with_timezone(&self.time_zone)
+                .format(&self.date_format)
+        )
+        .map_err(|_| {
+            Error::Export(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid date format {:?}", self.date_format),
+            ))
+        })?;
+        Ok(formatted)
+    }
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait ExportColumn: Copy {
+    type Record;
+
+    fn name(self) -> &'static str;
+
+    fn value(self, record: &Self::Record, options: &ExportOptions) -> Result<Value, Error>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueColumn {
+    Id,
+    Number,
+    Title,
+    Status,
+    Assignee,
+    Author,
+    Description,
+    CreatedAt,
+}
+
+impl IssueColumn {
+    pub const DEFAULT: [Self; 5] = [
+        Self::Number,
+        Self::Title,
+        Self::Status,
+        Self::Assignee,
+        Self::CreatedAt,
+    ];
+}
+
+impl ExportColumn for IssueColumn {
+    type Record = Issue;
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Number => "number",
+            Self::Title => "title",
+            Self::Status => "status",
+            Self::Assignee => "assignee",
+            Self::Author => "author",
+            Self::Description => "description",
+            Self::CreatedAt => "created_at",
+        }
+    }
+
+    fn value(self, issue: &Issue, options: &ExportOptions) -> Result<Value, Error> {
+        Ok(match self {
+            Self::Id => Value::from(issue.id.as_str()),
+            Self::Number => Value::from(issue.number),
+            Self::Title => Value::from(issue.title.as_str()),
+            Self::Status => Value::from(issue.status.as_str()),
+            Self::Assignee => issue.assignee.as_ref().map_or(Value::Null, |assignee| {
+                Value::from(assignee.username.as_str())
+            }),
+            Self::Author => Value::from(issue.created_by.username.as_str()),
+            Self::Description => issue
+                .description
+                .as_deref()
+                .map_or(Value::Null, Value::from),
+            Self::CreatedAt => Value::from(options.format_date(issue.created_at)?),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageColumn {
+    Id,
+    Author,
+    Text,
+    CreatedAt,
+}
+
+impl MessageColumn {
+    pub const DEFAULT: [Self; 3] = [Self::CreatedAt, Self::Author, Self::Text];
+}
+
+impl ExportColumn for MessageColumn {
+    type Record = Message;
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Author => "author",
+            Self::Text => "text",
+            Self::CreatedAt => "created_at",
+        }
+    }
+
+    fn value(self, message: &Message, options: &ExportOptions) -> Result<Value, Error> {
+        Ok(match self {
+            Self::Id => Value::from(message.id.as_str()),
+            Self::Author => Value::from(message.author.username.as_str()),
+            Self::Text => Value::from(message.text.as_str()),
+            Self::CreatedAt => Value::from(options.format_date(message.created_at)?),
+        })
+    }
+}
+
+pub trait RecordWriter<R> {
+    fn write_record(&mut self, record: &R) -> Result<(), Error>;
+}
+
+pub struct CsvExporter<W: Write, C: ExportColumn> {
+    writer: csv::Writer<W>,
+    columns: Vec<C>,
+    options: ExportOptions,
+}
+
+impl<W: Write, C: ExportColumn> CsvExporter<W, C> {
+    pub fn new(out: W, columns: &[C], options: ExportOptions) -> Result<Self, Error> {
+        let mut writer = csv::Writer::from_writer(out);
+        writer
+            .write_record(columns.iter().map(|column| column.name()))
+            .map_err(|error| Error::Export(error.into()))?;
+
+        Ok(Self {
+            writer,
+            columns: columns.to_vec(),
+            options,
+        })
+    }
+
+    pub fn finish(self) -> Result<W, Error> {
+        self.writer
+            .into_inner()
+            .map_err(|error| Error::Export(error.into_error()))
+    }
+}
+
+impl<W: Write, C: ExportColumn> RecordWriter<C::Record> for CsvExporter<W, C> {
+    fn write_record(&mut self, record: &C::Record) -> Result<(), Error> {
+        let cells = self
+            .columns
+            .iter()
+            .map(|column| {
+                Ok(match column.value(record, &self.options)? {
+                    Value::Null => String::new(),
+                    Value::String(text) => text,
+                    value => value.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        self.writer
+            .write_record(cells)
+            .map_err(|error| Error::Export(error.into()))
+    }
+}
+
+pub struct JsonLinesExporter<W: Write, C: ExportColumn> {
+    out: W,
+    columns: Vec<C>,
+    options: ExportOptions,
+}
+
+impl<W: Write, C: ExportColumn> JsonLinesExporter<W, C> {
+    pub fn new(out: W, columns: &[C], options: ExportOptions) -> Self {
+        Self {
+            out,
+            columns: columns.to_vec(),
+            options,
+        }
+    }
+
+    pub fn finish(mut self) -> Result<W, Error> {
+        self.out.flush().map_err(Error::Export)?;
+        Ok(self.out)
+    }
+}
+
+impl<W: Write, C: ExportColumn> RecordWriter<C::Record> for JsonLinesExporter<W, C> {
+    fn write_record(&mut self, record: &C::Record) -> Result<(), Error> {
+        let line: Map<String, Value> = self
+            .columns
+            .iter()
+            .map(|column| {
+                Ok((
+                    column.name().to_string(),
+                    column.value(record, &self.options)?,
+                ))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        serde_json::to_writer(&mut self.out, &line).map_err(|error| Error::Export(error.into()))?;
+        self.out.write_all(b"\n").map_err(Error::Export)
+    }
+}
+
+// The issue header is written right away, messages are appended as they are written.
+pub struct MarkdownTranscript<W: Write> {
+    out: W,
+    options: ExportOptions,
+}
+
+impl<W: Write> MarkdownTranscript<W> {
+    pub fn new(mut out: W, issue: &Issue, options: ExportOptions) -> Result<Self, Error> {
+        let assignee = issue
+            .assignee
+            .as_ref()
+            .map_or("Unassigned", |assignee| assignee.username.as_str());
+
+        write!(
+            out,
+            "# #{} {}\n\n- Status: {}\n- Assignee: {assignee}\n- Created by {} on {}\n",
+            issue.number,
+            issue.title,
+            issue.status,
+            issue.created_by.username,
+            options.format_date(issue.created_at)?
+        )
+        .map_err(Error::Export)?;
+        if let Some(description) = issue.description.as_deref().map(str::trim)
+            && !description.is_empty()
+        {
+            write!(out, "\n{description}\n").map_err(Error::Export)?;
+        }
+        out.write_all(b"\n## Conversation\n")
+            .map_err(Error::Export)?;
+
+        Ok(Self { out, options })
+    }
+
+    pub fn finish(mut self) -> Result<W, Error> {
+        self.out.flush().map_err(Error::Export)?;
+        Ok(self.out)
+    }
+}
+
+impl<W: Write> RecordWriter<Message> for MarkdownTranscript<W> {
+    fn write_record(&mut self, message: &Message) -> Result<(), Error> {
+        write!(
+            self.out,
+            "\n**{}** ({}):\n\n{}\n",
+            message.author.username,
+            self.options.format_date(message.created_at)?,
+            message.text.trim()
+        )
+        .map_err(Error::Export)
+    }
+}
+
+impl Client {
+    // Issues are fetched and written page by page, so whole projects can be exported without
+    // holding them in memory.
+    pub async fn export_issues(
+        &self,
+        mut query: IssuesQuery,
+        writer: &mut impl RecordWriter<Issue>,
+    ) -> Result<usize, Error> {
+        if query.top == 0 || query.top > DEFAULT_EXPORT_PAGE_SIZE {
+            query.top = DEFAULT_EXPORT_PAGE_SIZE;
+        }
+
+        let mut exported = 0;
+        loop {
+            let issues = self.get_issues_for_project(query.clone()).await?;
+            for issue in &issues {
+                writer.write_record(issue)?;
+            }
+
+            exported += issues.len();
+            if issues.len() < query.top {
+                return Ok(exported);
+            }
+            query.skip += issues.len();
+        }
+    }
+
+    pub async fn export_issue_messages(
+        &self,
+        issue: &Issue,
+        writer: &mut impl RecordWriter<Message>,
+    ) -> Result<usize, Error> {
+        // Walking from the oldest message writes each page as it arrives in conversation order.
+        let mut query = MessagesQuery::new(&issue.id);
+        query.sorting = MessagesSorting::FromOldestToNewest;
+
+        let mut exported = 0;
+        self.visit_channel_messages(&query, |page| {
+            for message in &page {
+                writer.write_record(message)?;
+            }
+            exported += page.len();
+            Ok(())
+        })
+        .await?;
+
+        Ok(exported)
+    }
+}
@@ -0,0 +1,420 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, ISSUE_FIELDS, Issue, Project, deserializers, encode_path_segment};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Commit {
+    pub id: String,
+    pub message: String,
+    pub author: CommitAuthor,
+    #[serde(
+        rename(deserialize = "authorDate"),
+        deserialize_with = "deserializers::deserialize_timestamp_millis"
+    )]
+    pub author_date: DateTime<Utc>,
+    #[serde(default)]
+    pub parents: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CommitAuthor {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FileChange {
+    #[serde(rename(deserialize = "changeType"))]
+    pub change_type: ChangeType,
+    #[serde(
+        rename(deserialize = "old"),
+        deserialize_with = "deserializers::deserialize_optional_path",
+        default
+    )]
+    pub old_path: Option<String>,
+    #[serde(
+        rename(deserialize = "new"),
+        deserialize_with = "deserializers::deserialize_optional_path",
+        default
+    )]
+    pub new_path: Option<String>,
+    pub revision: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChangeType {
+    Added,
+    Modified,
+    Deleted,
+}
+
+impl Commit {
+    // Space links commits to issues by mentioning the issue key, e.g. `ABC-T-42`.
+    pub fn referenced_issue_numbers(&self, project_key: &str) -> Vec<u32> {
+        let marker = format!("{project_key}-T-");
+        let mut numbers = vec![];
+        for (position, _) in self.message.match_indices(&marker) {
+            let preceded_by_word = self.message[..position]
+                .chars()
+                .next_back()
+                .is_some_and(|character| character.is_alphanumeric() || character == '-');
+            if preceded_by_word {
+                continue;
+            }
+
+            let digits: String = self.message[position + marker.len()..]
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect();
+            if let Ok(number) = digits.parse()
+                && !numbers.contains(&number)
+            {
+                numbers.push(number);
+            }
+        }
+
+        numbers
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Repository {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Branch {
+    #[serde(
+        rename(deserialize = "head"),
+        deserialize_with = "deserializers::deserialize_branch_name"
+    )]
+    pub name: String,
+    #[serde(rename(deserialize = "ref"))]
+    pub commit_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FileDiff {
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DiffLine {
+    pub text: String,
+    #[serde(rename(deserialize = "oldLineNum"), default)]
+    pub old_line: Option<u32>,
+    #[serde(rename(deserialize = "newLineNum"), default)]
+    pub new_line: Option<u32>,
+}
+
+impl DiffLine {
+    pub fn is_added(&self) -> bool {
+        self.old_line.is_none() && self.new_line.is_some()
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.old_line.is_some() && self.new_line.is_none()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ProjectRepositoriesApiResponse {
+    repos: Vec<Repository>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BranchesApiResponse {
+    data: Vec<Branch>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CommitsApiResponse {
+    data: Vec<Commit>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct FileChangesApiResponse {
+    pub(crate) data: Vec<FileChange>,
+}
+
+const COMMIT_FIELDS: &str = "id,message,author(name,email),authorDate,parents";
+
+// Not serialized directly, the filters are combined into one search query by `CommitsRequest`.
+#[derive(Debug, Clone)]
+pub struct CommitsQuery {
+    pub project_id: String,
+    pub repository: String,
+    pub author: Option<String>,
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+    pub branch: Option<String>,
+    pub text: Option<String>,
+    pub top: usize,
+    pub skip: usize,
+    pub fields: String,
+}
+
+#[derive(Serialize)]
+struct CommitsRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<String>,
+    #[serde(rename = "$top")]
+    top: usize,
+    #[serde(rename = "$skip")]
+    skip: usize,
+    #[serde(rename = "$fields")]
+    fields: &'a str,
+}
+
+impl CommitsQuery {
+    pub fn new(project_id: &str, repository: &str) -> Self {
+        Self {
+            project_id: project_id.to_string(),
+            repository: repository.to_string(),
+            author: None,
+            since: None,
+            until: None,
+            branch: None,
+            text: None,
+            top: 100000,
+            skip: 0,
+            fields: format!("data({COMMIT_FIELDS}),next,totalCount"),
+        }
+    }
+
+    // Filters are sent to Space as a single search query, e.g. `author:jane date:2025-01-01..`.
+    fn search_query(&self) -> Option<String> {
+        let mut terms = vec![];
+        if let Some(author) = &self.author {
+            terms.push(format!("author:{author}"));
+        }
+        if self.since.is_some() || self.until.is_some() {
+            let bound =
+                |date: Option<NaiveDate>| date.map(|date| date.to_string()).unwrap_or_default();
+            terms.push(format!("date:{}..{}", bound(self.since), bound(self.until)));
+        }
+        if let Some(branch) = &self.branch {
+            terms.push(format!("branch:{branch}"));
+        }
+        if let Some(text) = &self.text {
+            terms.push(text.clone());
+        }
+
+        (!terms.is_empty()).then(|| terms.join(" "))
+    }
+}
+
+impl Client {
+    pub async fn get_project_repositories(
+        &self,
+        project_id: &str,
+    ) -> Result<Vec<Repository>, Error> {
+        let url = format!("{}/api/http/projects/id:{project_id}", self.base_url);
+
+        let query = HashMap::from([("$fields", "repos(name,description)")]);
+
+        Ok(self
+            .send_request::<_, ProjectRepositoriesApiResponse>(&url, query)
+            .await?
+            .repos)
+    }
+
+    pub async fn get_branches(
+        &self,
+        project_id: &str,
+        repository: &str,
+    ) -> Result<Vec<Branch>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/repositories/{}/heads",
+            self.base_url,
+            encode_path_segment(repository)
+        );
+
+        let query = HashMap::from([
+            ("$fields", "data(head,ref),next,totalCount"),
+            ("$top", "100000"),
+        ]);
+
+        Ok(self
+            .send_request::<_, BranchesApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_commits(&self, query: CommitsQuery) -> Result<Vec<Commit>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{}/repositories/{}/commits",
+            self.base_url,
+            query.project_id,
+            encode_path_segment(&query.repository)
+        );
+
+        let request = CommitsRequest {
+            query: query.search_query(),
+            top: query.top,
+            skip: query.skip,
+            fields: &query.fields,
+        };
+
+        Ok(self
+            .send_request::<_, CommitsApiResponse>(&url, request)
+            .await?
+            .data)
+    }
+
+    pub async fn get_commit(
+        &self,
+        project_id: &str,
+        repository: &str,
+        revision: &str,
+    ) -> Result<Commit, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/repositories/{}/commits/{}",
+            self.base_url,
+            encode_path_segment(repository),
+            encode_path_segment(revision)
+        );
+
+        let query = HashMap::from([("$fields", COMMIT_FIELDS)]);
+
+        self.send_request::<_, Commit>(&url, query).await
+    }
+
+    pub async fn get_commit_changes(
+        &self,
+        project_id: &str,
+        repository: &str,
+        revision: &str,
+    ) -> Result<Vec<FileChange>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/repositories/{}/commits/{}/changes",
+            self.base_url,
+            encode_path_segment(repository),
+            encode_path_segment(revision)
+        );
+
+        let query = HashMap::from([
+            (
+                "$fields",
+                "data(changeType,old(path),new(path),revision),next,totalCount",
+            ),
+            ("$top", "100000"),
+        ]);
+
+        Ok(self
+            .send_request::<_, FileChangesApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_file_diff(
+        &self,
+        project_id: &str,
+        repository: &str,
+        base_revision: &str,
+        target_revision: &str,
+        path: &str,
+    ) -> Result<FileDiff, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/repositories/{}/text-diff",
+            self.base_url,
+            encode_path_segment(repository)
+        );
+
+        let query = HashMap::from([
+            ("baseCommit", base_revision),
+            ("targetCommit", target_revision),
+            ("file", path),
+        ]);
+
+        self.send_request::<_, FileDiff>(&url, query).await
+    }
+
+    pub async fn get_commit_issues(
+        &self,
+        project: &Project,
+        commit: &Commit,
+    ) -> Result<Vec<Issue>, Error> {
+        let mut issues = vec![];
+        for number in commit.referenced_issue_numbers(&project.key) {
+            let url = format!(
+                "{}/api/http/projects/id:{}/planning/issues/number:{number}",
+                self.base_url, project.id
+            );
+
+            let response = self
+                .request(reqwest::Method::GET, &url)
+                .query(&[("$fields", ISSUE_FIELDS)])
+                .send()
+                .await
+                .map_err(Error::Request)?;
+            // Commit messages can mention mistyped or deleted issues, those are skipped.
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                continue;
+            }
+
+            issues.push(
+                response
+                    .error_for_status()
+                    .map_err(Error::Request)?
+                    .json()
+                    .await
+                    .map_err(Error::ResponseParse)?,
+            );
+        }
+
+        Ok(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commit(message: &str) -> Commit {
+        Commit {
+            id: "a1b2c3d".to_string(),
+            message: message.to_string(),
+            author: CommitAuthor {
+                name: "Best Programmer".to_string(),
+                email: "best.programmer@example.com".to_string(),
+            },
+            author_date: DateTime::from_timestamp_millis(0).unwrap(),
+            parents: vec![],
+        }
+    }
+
+    #[test]
+    fn test_referenced_issue_numbers() {
+        let commit = commit("Fix login (TEST1-T-12)\n\nAlso touches TEST1-T-3 and TEST1-T-12.");
+
+        assert_eq!(commit.referenced_issue_numbers("TEST1"), vec![12, 3]);
+        assert!(commit.referenced_issue_numbers("OTHER").is_empty());
+    }
+
+    #[test]
+    fn test_referenced_issue_numbers_ignores_longer_keys() {
+        let commit = commit("MYTEST1-T-5 and TEST1-T- and TEST1-T-7");
+
+        assert_eq!(commit.referenced_issue_numbers("TEST1"), vec![7]);
+    }
+
+    #[test]
+    fn test_commits_search_query() {
+        let mut query = CommitsQuery::new("proj123", "space-rs");
+        assert!(query.search_query().is_none());
+
+        query.author = Some("jane".to_string());
+        query.since = NaiveDate::from_ymd_opt(2025, 1, 1);
+        query.text = Some("pagination".to_string());
+        assert_eq!(
+            query.search_query(),
+            Some("author:jane date:2025-01-01.. pagination".to_string())
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, ISSUE_FIELDS, Issue, IssuesQuery, deserializers};
+
+const SYNC_PAGE_SIZE: usize = 500;
+// Update times are assigned by the server when a change is stored, not when it becomes visible,
+// so changes committed close to the previous sync can show up with an older timestamp.
+const SYNC_CLOCK_SKEW: TimeDelta = TimeDelta::minutes(5);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct IssueSyncCursor {
+    pub updated_at: Option<DateTime<Utc>>,
+    pub issues: BTreeMap<String, DateTime<Utc>>,
+}
+
+impl IssueSyncCursor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IssueChanges {
+    pub created: Vec<Issue>,
+    pub updated: Vec<Issue>,
+    pub deleted: Vec<String>,
+    pub cursor: IssueSyncCursor,
+}
+
+impl IssueChanges {
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.updated.is_empty() && self.deleted.is_empty()
+    }
+}
+
+#[derive(Deserialize)]
+struct SyncedIssue {
+    #[serde(flatten)]
+    issue: Issue,
+    #[serde(
+        rename = "updateTime",
+        deserialize_with = "deserializers::deserialize_space_date"
+    )]
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct SyncedIssuesApiResponse {
+    data: Vec<SyncedIssue>,
+}
+
+#[derive(Deserialize)]
+struct IssueIdsApiResponse {
+    #[serde(deserialize_with = "deserializers::deserialize_ids")]
+    data: Vec<String>,
+}
+
+impl Client {
+    // Walks the issues from the most recently updated one back to the cursor, so only changes
+    // are downloaded. Deletions are found by comparing the known ids against a listing of ids.
+    pub async fn sync_issues(
+        &self,
+        project_id: &str,
+        cursor: &IssueSyncCursor,
+    ) -> Result<IssueChanges, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/planning/issues",
+            self.base_url
+        );
+
+        let mut next = cursor.clone();
+        let mut deleted = vec![];
+        if !cursor.issues.is_empty() {
+            let mut missing = self.get_missing_issue_ids(project_id, &next).await?;
+            // Paging by offset can skip an id when another issue is deleted mid-listing, so
+            // anything missing is confirmed by a second listing.
+            if !missing.is_empty() {
+                let confirmed = self.get_missing_issue_ids(project_id, &next).await?;
+                missing.retain(|id| confirmed.contains(id));
+            }
+            next.issues.retain(|id, _| !missing.contains(id));
+            deleted.extend(missing);
+        }
+
+        let since = cursor
+            .updated_at
+            .map(|updated_at| updated_at - SYNC_CLOCK_SKEW);
+        let mut query = IssuesQuery::new(project_id);
+        query.sorting = "UPDATED".to_string();
+        query.descending = true;
+        query.top = SYNC_PAGE_SIZE;
+        query.fields = format!("data({ISSUE_FIELDS},updateTime),next,totalCount");
+
+        let mut seen = HashSet::new();
+        let mut created = vec![];
+        let mut updated = vec![];
+        'pages: loop {
+            let page = self
+                .send_request::<_, SyncedIssuesApiResponse>(&url, query.clone())
+                .await?
+                .data;
+            let page_size = page.len();
+
+            for SyncedIssue { issue, updated_at } in page {
+                if since.is_some_and(|since| updated_at < since) {
+                    break 'pages;
+                }
+                // Issues updated while paging move to the front and push others onto the next
+                // page a second time.
+                if !seen.insert(issue.id.clone()) {
+                    continue;
+                }
+
+                next.updated_at = next.updated_at.max(Some(updated_at));
+                match next.issues.entry(issue.id.clone()) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(updated_at);
+                        created.push(issue);
+                    }
+                    Entry::Occupied(mut entry) if *entry.get() < updated_at => {
+                        entry.insert(updated_at);
+                        updated.push(issue);
+                    }
+                    Entry::Occupied(_) => {}
+                }
+            }
+
+            if page_size < query.top {
+                break;
+            }
+            query.skip += page_size;
+        }
+
+        created.reverse();
+        updated.reverse();
+
+        Ok(IssueChanges {
+            created,
+            updated,
+            deleted,
+            cursor: next,
+        })
+    }
+
+    async fn get_missing_issue_ids(
+        &self,
+        project_id: &str,
+        cursor: &IssueSyncCursor,
+    ) -> Result<Vec<String>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/planning/issues",
+            self.base_url
+        );
+
+        let mut query = IssuesQuery::new(project_id);
+        query.sorting = "CREATED".to_string();
+        query.descending = false;
+        query.top = SYNC_PAGE_SIZE;
+        query.fields = "data(id),next,totalCount".to_string();
+
+        let mut existing = HashSet::new();
+        loop {
+            let page = self
+                .send_request::<_, IssueIdsApiResponse>(&url, query.clone())
+                .await?
+                .data;
+            let page_size = page.len();
+            existing.extend(page);
+
+            if page_size < query.top {
+                break;
+            }
+            query.skip += page_size;
+        }
+
+        Ok(cursor
+            .issues
+            .keys()
+            .filter(|id| !existing.contains(*id))
+            .cloned()
+            .collect())
+    }
+}
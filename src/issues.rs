@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, CustomFieldInput, Error, ISSUE_FIELDS, Issue, deserializers};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IssueStatus {
+    pub id: String,
+    pub name: String,
+    pub resolved: bool,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IssueTag {
+    pub id: String,
+    pub name: String,
+    #[serde(
+        rename(deserialize = "parent"),
+        deserialize_with = "deserializers::deserialize_optional_id",
+        default
+    )]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct IssueTagsApiResponse {
+    data: Vec<IssueTag>,
+}
+
+#[derive(Serialize)]
+struct NewIssueTag<'a> {
+    path: [&'a str; 1],
+    #[serde(rename = "parentTagId", skip_serializing_if = "Option::is_none")]
+    parent_id: Option<&'a str>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewIssue {
+    #[serde(skip_serializing)]
+    pub project_id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename(serialize = "status"))]
+    pub status_id: String,
+    #[serde(
+        rename(serialize = "assignee"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub assignee_id: Option<String>,
+    #[serde(rename(serialize = "tags"), skip_serializing_if = "Vec::is_empty")]
+    pub tag_ids: Vec<String>,
+    #[serde(
+        rename(serialize = "customFields"),
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub custom_fields: Vec<CustomFieldInput>,
+}
+
+impl NewIssue {
+    pub fn new(project_id: &str, title: &str, status_id: &str) -> Self {
+        Self {
+            project_id: project_id.to_string(),
+            title: title.to_string(),
+            description: None,
+            status_id: status_id.to_string(),
+            assignee_id: None,
+            tag_ids: vec![],
+            custom_fields: vec![],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct IssueUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename(serialize = "status"), skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<String>,
+    #[serde(
+        rename(serialize = "assignee"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub assignee_id: Option<String>,
+}
+
+impl Client {
+    pub async fn get_issue_statuses(&self, project_id: &str) -> Result<Vec<IssueStatus>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/planning/issues/statuses",
+            self.base_url
+        );
+
+        self.send_request::<_, Vec<IssueStatus>>(&url, ()).await
+    }
+
+    pub async fn get_issue_tags(&self, project_id: &str) -> Result<Vec<IssueTag>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/planning/tags",
+            self.base_url
+        );
+
+        let query = [
+            ("$fields", "data(id,name,parent(id)),next,totalCount"),
+            ("$top", "100000"),
+        ];
+
+        Ok(self
+            .send_request::<_, IssueTagsApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn create_issue_tag(
+        &self,
+        project_id: &str,
+        name: &str,
+        parent_id: Option<&str>,
+    ) -> Result<IssueTag, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/planning/tags?$fields=id,name,parent(id)",
+            self.base_url
+        );
+
+        let body = NewIssueTag {
+            path: [name],
+            parent_id,
+        };
+
+        self.send_json_request::<_, IssueTag>(reqwest::Method::POST, &url, body)
+            .await
+    }
+
+    pub async fn create_issue(&self, issue: NewIssue) -> Result<Issue, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{}/planning/issues?$fields={ISSUE_FIELDS}",
+            self.base_url, issue.project_id
+        );
+
+        self.send_json_request::<_, Issue>(reqwest::Method::POST, &url, issue)
+            .await
+    }
+
+    pub async fn update_issue(
+        &self,
+        project_id: &str,
+        issue_id: &str,
+        update: IssueUpdate,
+    ) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/planning/issues/id:{issue_id}",
+            self.base_url
+        );
+
+        self.send_request_without_response(reqwest::Method::PATCH, &url, Some(update))
+            .await
+    }
+}
@@ -1,10 +1,121 @@
+mod absences;
+mod application_payloads;
+mod applications;
+mod attachments;
+mod automation;
+mod backup;
+#[cfg(feature = "blocking")]
+mod blocking;
+mod blog;
+mod chats;
+mod code_reviews;
+mod custom_fields;
 mod deserializers;
-
-use std::collections::HashMap;
-
-use chrono::{DateTime, Utc};
+mod documents;
+#[cfg(feature = "export")]
+mod export;
+mod git;
+mod issue_sync;
+mod issues;
+mod meetings;
+mod merge_requests;
+mod messages;
+mod packages;
+mod profiles;
+mod serializers;
+mod teams;
+mod todos;
+mod webhook_events;
+mod webhooks;
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
 use serde::{Deserialize, Serialize};
 
+pub use absences::{Absence, AbsenceApproval, AbsenceReason, AbsencesQuery, NewAbsence};
+pub use application_payloads::{
+    AppPublicationCheck, ApplicationCommand, ApplicationHandler, ApplicationPayload,
+    ApplicationResponse, Commands, InitPayload, ListCommandsPayload, MenuActionContext,
+    MenuActionPayload, MenuActionResult, MessageContext, MessagePayload,
+    handle_application_payload, parse_application_payload,
+};
+pub use applications::{
+    Application, ApplicationIdentifier, GrantedRight, PermissionContext, Right, RightStatus,
+};
+pub use attachments::Attachment;
+pub use automation::{ExecutionStatus, Job, JobExecution, JobsQuery, StepExecution};
+pub use backup::{
+    ArchivedIssue, ArchivedMessage, ArchivedProject, ArchivedTag, BACKUP_FORMAT_VERSION,
+    BackupSummary, RemappedStatus, RestoreOptions, RestoreSummary,
+};
+#[cfg(feature = "blocking")]
+pub use blocking::BlockingClient;
+pub use blog::{Article, ArticleUpdate, ArticlesQuery, NewArticle};
+pub use chats::{Channel, ChannelIdentifier, ChannelsQuery, NewChannel};
+pub use code_reviews::{
+    CodeReview, CodeReviewParticipant, CodeReviewParticipantRole, CodeReviewState,
+    CodeReviewsQuery, ReviewerState,
+};
+pub use custom_fields::{
+    CustomField, CustomFieldInput, CustomFieldInputValue, CustomFieldType, CustomFieldValue,
+    EnumValue, NewCustomField,
+};
+pub use documents::{
+    Document, DocumentBody, DocumentFolder, DocumentSpace, DocumentUpdate, NewDocument,
+};
+#[cfg(feature = "export")]
+pub use export::{
+    CsvExporter, ExportColumn, ExportOptions, IssueColumn, JsonLinesExporter, MarkdownTranscript,
+    MessageColumn, RecordWriter,
+};
+pub use git::{
+    Branch, ChangeType, Commit, CommitAuthor, CommitsQuery, DiffLine, FileChange, FileDiff,
+    Repository,
+};
+pub use issue_sync::{IssueChanges, IssueSyncCursor};
+pub use issues::{IssueStatus, IssueTag, IssueUpdate, NewIssue};
+pub use meetings::{
+    Meeting, MeetingSchedule, MeetingUpdate, MeetingsQuery, NewMeeting, Recurrence,
+    RecurrenceFrequency,
+};
+pub use merge_requests::{
+    MergeOptions, MergeResult, MergeState, MergeStatus, MergeStrategy, NewMergeRequest,
+};
+pub use messages::{
+    ChatMessage, MessageAction, MessageButton, MessageButtonStyle, MessageElement, MessageField,
+    MessageSection, MessageStyle, MessageText, MessageTextSize,
+};
+pub use packages::{
+    Package, PackageRepository, PackageType, PackageVersion, PackageVersionDetails,
+};
+pub use profiles::{
+    Location, Profile, ProfileLocation, WorkingDay, WorkingDays, WorkingInterval, WorkingWindow,
+    overlapping_working_hours,
+};
+pub use teams::{Membership, MembershipsQuery, Team, TeamNode, TeamsQuery};
+pub use todos::{NewTodoItem, TodoItem, TodoItemUpdate, TodoItemsQuery};
+pub use webhook_events::{
+    PUBLIC_KEY_SIGNATURE_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER, WebhookEvent, WebhookPayload,
+    WebhookVerifier, parse_webhook_payload,
+};
+pub use webhooks::{
+    EventFilter, NewSubscription, NewWebhook, Subscription, SubscriptionUpdate, Webhook,
+    WebhookEventType, WebhookUpdate,
+};
+
+const MAX_MESSAGES_BATCH_SIZE: usize = 500;
+// Identifiers such as `channel:name:general` keep their prefixes readable in paths.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~')
+    .remove(b':');
+const ISSUE_FIELDS: &str =
+    "assignee(username,id),id,number,status,title,description,createdBy,commentsCount,creationTime";
+
 pub struct Client {
     #[allow(clippy::struct_field_names)]
     http_client: reqwest::Client,
@@ -18,6 +129,34 @@ pub enum Error {
     Request(reqwest::Error),
     #[error("Response parse error: {0}")]
     ResponseParse(reqwest::Error),
+    #[error("Invalid channel identifier: {0}")]
+    InvalidChannelIdentifier(String),
+    #[error("Merge request has conflicts in: {}", .0.join(", "))]
+    MergeConflicts(Vec<String>),
+    #[error("Merge request {0} is already merged")]
+    AlreadyMerged(String),
+    #[error("Merge request {0} is closed")]
+    MergeRequestClosed(String),
+    #[error("Merge request cannot be merged: {0}")]
+    MergeBlocked(String),
+    #[error("Merge was rejected: {0}")]
+    MergeRejected(String),
+    #[error("Invalid subscription: {0}")]
+    InvalidSubscription(String),
+    #[error("Webhook signature verification failed: {0}")]
+    WebhookSignature(String),
+    #[error("Webhook payload parse error: {0}")]
+    WebhookPayload(serde_json::Error),
+    #[error("Application payload parse error: {0}")]
+    ApplicationPayload(serde_json::Error),
+    #[error("Export error: {0}")]
+    Export(std::io::Error),
+    #[error("Backup archive error: {0}")]
+    Archive(std::io::Error),
+    #[error("Unsupported backup archive: {0}")]
+    UnsupportedArchive(String),
+    #[error("Runtime error: {0}")]
+    Runtime(std::io::Error),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -71,6 +210,14 @@ struct MessagesApiResponse {
     messages: Vec<Message>,
     #[serde(rename(deserialize = "nextStartFromDate"))]
     next_start_from_date: Option<PaginationDate>,
+    #[serde(rename(deserialize = "orgLimitReached"), default)]
+    org_limit_reached: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessagesBatch {
+    pub messages: Vec<Message>,
+    pub org_limit_reached: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -90,6 +237,18 @@ pub struct IssuesQuery {
     pub skip: usize,
     #[serde(rename(serialize = "$fields"))]
     pub fields: String,
+    #[serde(
+        rename(serialize = "assigneeId"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub assignee_id: Option<String>,
+    #[serde(
+        rename(serialize = "statuses"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub status_id: Option<String>,
+    #[serde(rename(serialize = "query"), skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
 }
 
 impl IssuesQuery {
@@ -100,37 +259,98 @@ impl IssuesQuery {
             descending: true,
             top: 100000,
             skip: 0,
-            fields: "data(assignee(username,id),id,number,status,title,description,createdBy,commentsCount,creationTime),next,totalCount".to_string(),
+            fields: format!("data({ISSUE_FIELDS}),next,totalCount"),
+            assignee_id: None,
+            status_id: None,
+            text: None,
         }
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MessagesQuery {
-    pub channel: String,
-    pub sorting: String,
+    pub channel: ChannelIdentifier,
+    pub sorting: MessagesSorting,
     #[serde(rename(serialize = "batchSize"))]
     pub batch_size: usize,
     #[serde(rename(serialize = "$fields"))]
     pub fields: String,
     #[serde(rename(serialize = "startFromDate"))]
     pub start_from_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing)]
+    pub newer_than: Option<DateTime<Utc>>,
+    #[serde(skip_serializing)]
+    pub older_than: Option<DateTime<Utc>>,
 }
 
 impl MessagesQuery {
     pub fn new(issue_id: &str) -> Self {
+        Self::for_channel(ChannelIdentifier::Issue(issue_id.to_string()))
+    }
+
+    pub fn for_channel(channel: ChannelIdentifier) -> Self {
         Self {
-            channel: format!("issue:id:{issue_id}"),
-            sorting: "FromNewestToOldest".to_string(),
+            channel,
+            sorting: MessagesSorting::FromNewestToOldest,
             batch_size: 50,
             fields: "nextStartFromDate,orgLimitReached,messages(id,author,created,text)"
                 .to_string(),
             start_from_date: None,
+            newer_than: None,
+            older_than: None,
+        }
+    }
+
+    // Walks forward from the timestamp, so only the new part of the channel is downloaded.
+    #[must_use]
+    pub fn newer_than(mut self, date: DateTime<Utc>) -> Self {
+        self.sorting = MessagesSorting::FromOldestToNewest;
+        self.start_from_date = Some(date);
+        self.newer_than = Some(date);
+        self
+    }
+
+    #[must_use]
+    pub fn older_than(mut self, date: DateTime<Utc>) -> Self {
+        self.sorting = MessagesSorting::FromNewestToOldest;
+        self.start_from_date = Some(date);
+        self.older_than = Some(date);
+        self
+    }
+
+    // Both bounds are exclusive.
+    #[must_use]
+    pub fn within(self, since: DateTime<Utc>, until: DateTime<Utc>) -> Self {
+        Self {
+            older_than: Some(until),
+            ..self.newer_than(since)
+        }
+    }
+
+    fn contains(&self, date: DateTime<Utc>) -> bool {
+        self.newer_than.is_none_or(|newer_than| date > newer_than)
+            && self.older_than.is_none_or(|older_than| date < older_than)
+    }
+
+    fn is_past_window(&self, date: DateTime<Utc>) -> bool {
+        match self.sorting {
+            MessagesSorting::FromNewestToOldest => {
+                self.newer_than.is_some_and(|newer_than| date <= newer_than)
+            }
+            MessagesSorting::FromOldestToNewest => {
+                self.older_than.is_some_and(|older_than| date >= older_than)
+            }
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessagesSorting {
+    FromNewestToOldest,
+    FromOldestToNewest,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Project {
     pub id: String,
     pub name: String,
@@ -143,6 +363,31 @@ pub struct ProjectsApiResponse {
     data: Vec<Project>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewProject {
+    #[serde(serialize_with = "serializers::serialize_project_key")]
+    pub key: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub private: bool,
+}
+
+impl NewProject {
+    pub fn new(key: &str, name: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            name: name.to_string(),
+            description: None,
+            private: false,
+        }
+    }
+}
+
+pub(crate) fn encode_path_segment(segment: &str) -> String {
+    utf8_percent_encode(segment, PATH_SEGMENT).to_string()
+}
+
 impl Client {
     pub fn new(base_url: &str, auth_token: &str, http_client: Option<reqwest::Client>) -> Self {
         Self {
@@ -161,6 +406,13 @@ impl Client {
             .data)
     }
 
+    pub async fn create_project(&self, project: NewProject) -> Result<Project, Error> {
+        let url = format!("{}/api/http/projects", self.base_url);
+
+        self.send_json_request::<_, Project>(reqwest::Method::POST, &url, project)
+            .await
+    }
+
     pub async fn get_issues_for_project(&self, query: IssuesQuery) -> Result<Vec<Issue>, Error> {
         let url = format!(
             "{}/api/http/projects/id:{}/planning/issues",
@@ -183,51 +435,107 @@ impl Client {
             self.base_url
         );
 
-        let query = HashMap::from([(
-            "$fields",
-            "assignee(username,id),id,number,status,title,description,createdBy,commentsCount,creationTime",
-        )]);
+        let query = HashMap::from([("$fields", ISSUE_FIELDS)]);
 
         self.send_request::<_, Issue>(&url, query).await
     }
 
     pub async fn get_issue_messages(&self, query: MessagesQuery) -> Result<Vec<Message>, Error> {
+        self.get_channel_messages(query).await
+    }
+
+    // Drops the `orgLimitReached` flag, use `get_channel_messages_batch` when it matters.
+    pub async fn get_channel_messages(&self, query: MessagesQuery) -> Result<Vec<Message>, Error> {
+        Ok(self.get_channel_messages_batch(query).await?.messages)
+    }
+
+    pub async fn get_channel_messages_batch(
+        &self,
+        query: MessagesQuery,
+    ) -> Result<MessagesBatch, Error> {
+        let mut messages: Vec<Message> = vec![];
+        let org_limit_reached = self
+            .visit_channel_messages(&query, |page| {
+                messages.extend(page);
+                Ok(())
+            })
+            .await?;
+
+        messages.sort_by_key(|message| message.created_at);
+        Ok(MessagesBatch {
+            messages,
+            org_limit_reached,
+        })
+    }
+
+    // Hands each page to `on_page` as soon as it arrives, in the order of the query's sorting.
+    // Returns whether the organisation limit cut the channel short.
+    pub(crate) async fn visit_channel_messages(
+        &self,
+        query: &MessagesQuery,
+        mut on_page: impl FnMut(Vec<Message>) -> Result<(), Error>,
+    ) -> Result<bool, Error> {
         let url = format!("{}/api/http/chats/messages", self.base_url);
 
         let mut actual_query = query.clone();
-        let mut messages: HashMap<String, Message> = HashMap::new();
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut org_limit_reached = false;
         loop {
             let response = self
                 .send_request::<_, MessagesApiResponse>(&url, actual_query.clone())
                 .await?;
 
-            if response.messages.is_empty() {
+            org_limit_reached |= response.org_limit_reached;
+            let page_size = response.messages.len();
+            let Some(last_created_at) = response.messages.last().map(|message| message.created_at)
+            else {
                 break;
-            }
+            };
 
-            let messages_more_than_batch_size = &response.messages.len() < &query.batch_size;
+            let mut new_messages = 0;
+            let mut past_window = false;
+            let mut page = vec![];
+            for message in response.messages {
+                if query.is_past_window(message.created_at) {
+                    past_window = true;
+                } else if seen.insert(message.id.clone()) {
+                    new_messages += 1;
+                    if query.contains(message.created_at) {
+                        page.push(message);
+                    }
+                }
+            }
+            if !page.is_empty() {
+                on_page(page)?;
+            }
 
-            response.messages.into_iter().for_each(|message| {
-                messages.insert(message.id.clone(), message);
-            });
+            if past_window || org_limit_reached || response.next_start_from_date.is_none() {
+                break;
+            }
 
-            match response.next_start_from_date {
-                Some(date) => {
-                    actual_query.start_from_date = Some(date.iso);
-                }
-                None => {
+            // A page without anything new is either the end of the channel or a run of messages
+            // sharing one timestamp that is longer than the batch, which a bigger batch gets past.
+            if new_messages == 0 {
+                if page_size < actual_query.batch_size
+                    || actual_query.batch_size >= MAX_MESSAGES_BATCH_SIZE
+                {
                     break;
                 }
-            };
-
-            if messages_more_than_batch_size {
-                break;
+                actual_query.batch_size =
+                    (actual_query.batch_size * 2).min(MAX_MESSAGES_BATCH_SIZE);
+                continue;
             }
+
+            // Restart one millisecond before the last message so that messages sharing its
+            // timestamp are fetched again rather than skipped, duplicates are dropped by id.
+            actual_query.batch_size = query.batch_size;
+            actual_query.start_from_date = Some(match query.sorting {
+                MessagesSorting::FromNewestToOldest => last_created_at + TimeDelta::milliseconds(1),
+                MessagesSorting::FromOldestToNewest => last_created_at - TimeDelta::milliseconds(1),
+            });
         }
 
-        let mut res = messages.into_values().collect::<Vec<Message>>();
-        res.sort_by(|a, b| a.created_at.cmp(&b.created_at));
-        Ok(res)
+        Ok(org_limit_reached)
     }
 
     async fn send_request<TQuery, TResponse>(
@@ -240,10 +548,7 @@ impl Client {
         TResponse: for<'de> Deserialize<'de> + Send,
     {
         let result = self
-            .http_client
-            .get(url)
-            .header("Authorization", format!("Bearer {}", self.auth_token))
-            .header("Accept", "application/json")
+            .request(reqwest::Method::GET, url)
             .query(&query)
             .send()
             .await
@@ -251,4 +556,71 @@ impl Client {
 
         result.json().await.map_err(Error::ResponseParse)
     }
+
+    async fn send_json_request<TBody, TResponse>(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: TBody,
+    ) -> Result<TResponse, Error>
+    where
+        TBody: Serialize + Send,
+        TResponse: for<'de> Deserialize<'de> + Send,
+    {
+        self.send_json_request_with_query(method, url, (), body)
+            .await
+    }
+
+    async fn send_json_request_with_query<TQuery, TBody, TResponse>(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        query: TQuery,
+        body: TBody,
+    ) -> Result<TResponse, Error>
+    where
+        TQuery: Serialize + Send,
+        TBody: Serialize + Send,
+        TResponse: for<'de> Deserialize<'de> + Send,
+    {
+        let result = self
+            .request(method, url)
+            .query(&query)
+            .json(&body)
+            .send()
+            .await
+            .map_err(Error::Request)?;
+
+        result.json().await.map_err(Error::ResponseParse)
+    }
+
+    async fn send_request_without_response<TBody>(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<TBody>,
+    ) -> Result<(), Error>
+    where
+        TBody: Serialize + Send,
+    {
+        let mut request = self.request(method, url);
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        request
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(Error::Request)?;
+
+        Ok(())
+    }
+
+    fn request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        self.http_client
+            .request(method, url)
+            .header("Authorization", format!("Bearer {}", self.auth_token))
+            .header("Accept", "application/json")
+    }
 }
@@ -0,0 +1,236 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, Member, deserializers, serializers};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Meeting {
+    pub id: String,
+    pub summary: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(rename(deserialize = "occurrenceRule"))]
+    pub schedule: MeetingSchedule,
+    #[serde(rename(deserialize = "profiles"), default)]
+    pub participants: Vec<Member>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingSchedule {
+    #[serde(deserialize_with = "deserializers::deserialize_space_date")]
+    pub start: DateTime<Utc>,
+    #[serde(deserialize_with = "deserializers::deserialize_space_date")]
+    pub end: DateTime<Utc>,
+    #[serde(
+        rename = "timezone",
+        serialize_with = "serializers::serialize_time_zone",
+        deserialize_with = "deserializers::deserialize_optional_id",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub time_zone: Option<String>,
+    #[serde(default)]
+    pub all_day: bool,
+    #[serde(
+        rename = "recurrenceRule",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub recurrence: Option<Recurrence>,
+}
+
+impl MeetingSchedule {
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self {
+            start,
+            end,
+            time_zone: None,
+            all_day: false,
+            recurrence: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Recurrence {
+    #[serde(rename = "freq")]
+    pub frequency: RecurrenceFrequency,
+    pub interval: u32,
+    #[serde(
+        deserialize_with = "deserializers::deserialize_optional_space_local_date",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub until: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub count: Option<u32>,
+}
+
+impl Recurrence {
+    pub fn new(frequency: RecurrenceFrequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            until: None,
+            count: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct MeetingsApiResponse {
+    data: Vec<Meeting>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MeetingsQuery {
+    #[serde(
+        rename(serialize = "profiles"),
+        serialize_with = "serializers::serialize_optional_profile_identifier"
+    )]
+    pub profile_id: Option<String>,
+    #[serde(rename(serialize = "startingAfter"))]
+    pub starting_after: Option<DateTime<Utc>>,
+    #[serde(rename(serialize = "endingBefore"))]
+    pub ending_before: Option<DateTime<Utc>>,
+    #[serde(rename(serialize = "$top"))]
+    pub top: usize,
+    #[serde(rename(serialize = "$skip"))]
+    pub skip: usize,
+    #[serde(rename(serialize = "$fields"))]
+    pub fields: String,
+}
+
+impl MeetingsQuery {
+    pub fn new(starting_after: DateTime<Utc>, ending_before: DateTime<Utc>) -> Self {
+        Self {
+            profile_id: None,
+            starting_after: Some(starting_after),
+            ending_before: Some(ending_before),
+            top: 100000,
+            skip: 0,
+            fields: "data(id,summary,description,occurrenceRule(start,end,timezone(id),allDay,recurrenceRule),profiles(id,username)),next,totalCount".to_string(),
+        }
+    }
+
+    pub fn for_profile(
+        profile_id: &str,
+        starting_after: DateTime<Utc>,
+        ending_before: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            profile_id: Some(profile_id.to_string()),
+            ..Self::new(starting_after, ending_before)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewMeeting {
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename(serialize = "occurrenceRule"))]
+    pub schedule: MeetingSchedule,
+    #[serde(
+        rename(serialize = "profiles"),
+        serialize_with = "serializers::serialize_identifiers"
+    )]
+    pub participant_ids: Vec<String>,
+}
+
+impl NewMeeting {
+    pub fn new(summary: &str, schedule: MeetingSchedule) -> Self {
+        Self {
+            summary: summary.to_string(),
+            description: None,
+            schedule,
+            participant_ids: vec![],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MeetingUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(
+        rename(serialize = "occurrenceRule"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub schedule: Option<MeetingSchedule>,
+    #[serde(
+        rename(serialize = "profiles"),
+        serialize_with = "serializers::serialize_optional_identifiers",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub participant_ids: Option<Vec<String>>,
+}
+
+impl Client {
+    pub async fn get_meetings(&self, query: MeetingsQuery) -> Result<Vec<Meeting>, Error> {
+        let url = format!("{}/api/http/calendars/meetings", self.base_url);
+
+        Ok(self
+            .send_request::<_, MeetingsApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_profile_meetings(
+        &self,
+        profile_id: &str,
+        starting_after: DateTime<Utc>,
+        ending_before: DateTime<Utc>,
+    ) -> Result<Vec<Meeting>, Error> {
+        self.get_meetings(MeetingsQuery::for_profile(
+            profile_id,
+            starting_after,
+            ending_before,
+        ))
+        .await
+    }
+
+    pub async fn create_meeting(&self, meeting: NewMeeting) -> Result<Meeting, Error> {
+        let url = format!("{}/api/http/calendars/meetings", self.base_url);
+
+        self.send_json_request::<_, Meeting>(reqwest::Method::POST, &url, meeting)
+            .await
+    }
+
+    pub async fn update_meeting(
+        &self,
+        meeting_id: &str,
+        update: MeetingUpdate,
+    ) -> Result<Meeting, Error> {
+        let url = format!(
+            "{}/api/http/calendars/meetings/id:{meeting_id}",
+            self.base_url
+        );
+
+        self.send_json_request::<_, Meeting>(reqwest::Method::PATCH, &url, update)
+            .await
+    }
+
+    pub async fn cancel_meeting(&self, meeting_id: &str) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/calendars/meetings/id:{meeting_id}",
+            self.base_url
+        );
+
+        self.send_request_without_response::<()>(reqwest::Method::DELETE, &url, None)
+            .await
+    }
+}
@@ -0,0 +1,243 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, CodeReview, CodeReviewParticipantRole, Error, deserializers, serializers};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewMergeRequest {
+    #[serde(skip_serializing)]
+    pub project_id: String,
+    pub repository: String,
+    #[serde(rename(serialize = "sourceBranch"))]
+    pub source_branch: String,
+    #[serde(rename(serialize = "targetBranch"))]
+    pub target_branch: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(serialize_with = "serializers::serialize_reviewers")]
+    pub reviewers: Vec<String>,
+}
+
+impl NewMergeRequest {
+    pub fn new(
+        project_id: &str,
+        repository: &str,
+        source_branch: &str,
+        target_branch: &str,
+        title: &str,
+    ) -> Self {
+        Self {
+            project_id: project_id.to_string(),
+            repository: repository.to_string(),
+            source_branch: source_branch.to_string(),
+            target_branch: target_branch.to_string(),
+            title: title.to_string(),
+            description: None,
+            reviewers: vec![],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MergeStatus {
+    pub state: MergeState,
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(
+        deserialize_with = "deserializers::deserialize_conflicting_paths",
+        default
+    )]
+    pub conflicts: Vec<String>,
+}
+
+impl MergeStatus {
+    pub fn is_mergeable(&self) -> bool {
+        self.state == MergeState::Mergeable
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeState {
+    Mergeable,
+    Conflicts,
+    Blocked,
+    Merged,
+    Closed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    FastForward,
+    FastForwardOnly,
+    NoFastForward,
+    Squash,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MergeOptions {
+    pub strategy: MergeStrategy,
+    pub delete_source_branch: bool,
+    pub commit_message: Option<String>,
+}
+
+impl MergeOptions {
+    pub fn new(strategy: MergeStrategy) -> Self {
+        Self {
+            strategy,
+            delete_source_branch: false,
+            commit_message: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MergeResult {
+    #[serde(rename(deserialize = "commitId"))]
+    pub commit_id: String,
+    #[serde(rename(deserialize = "sourceBranchDeleted"), default)]
+    pub source_branch_deleted: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MergeRequestBody {
+    merge_mode: &'static str,
+    squash: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    squashed_commit_message: Option<String>,
+    delete_source_branch: bool,
+}
+
+impl From<MergeOptions> for MergeRequestBody {
+    fn from(options: MergeOptions) -> Self {
+        let merge_mode = match options.strategy {
+            MergeStrategy::FastForward | MergeStrategy::Squash => "FF",
+            MergeStrategy::FastForwardOnly => "FF_ONLY",
+            MergeStrategy::NoFastForward => "NO_FF",
+        };
+
+        Self {
+            merge_mode,
+            squash: options.strategy == MergeStrategy::Squash,
+            squashed_commit_message: options.commit_message,
+            delete_source_branch: options.delete_source_branch,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct MergeApiResponse {
+    success: bool,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(flatten)]
+    result: Option<MergeResult>,
+}
+
+#[derive(Serialize)]
+struct ParticipantRequest {
+    role: CodeReviewParticipantRole,
+}
+
+impl Client {
+    pub async fn create_merge_request(
+        &self,
+        merge_request: NewMergeRequest,
+    ) -> Result<CodeReview, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{}/code-reviews/merge-requests",
+            self.base_url, merge_request.project_id
+        );
+
+        self.send_json_request::<_, CodeReview>(reqwest::Method::POST, &url, merge_request)
+            .await
+    }
+
+    pub async fn add_merge_request_reviewers(
+        &self,
+        project_id: &str,
+        review_id: &str,
+        profile_ids: &[&str],
+    ) -> Result<(), Error> {
+        for profile_id in profile_ids {
+            let url = format!(
+                "{}/api/http/projects/id:{project_id}/code-reviews/id:{review_id}/participants/id:{profile_id}",
+                self.base_url
+            );
+
+            self.send_request_without_response(
+                reqwest::Method::POST,
+                &url,
+                Some(ParticipantRequest {
+                    role: CodeReviewParticipantRole::Reviewer,
+                }),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_merge_status(
+        &self,
+        project_id: &str,
+        review_id: &str,
+    ) -> Result<MergeStatus, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/code-reviews/id:{review_id}/merge-status",
+            self.base_url
+        );
+
+        let query = [("$fields", "state,reason,conflicts(path)")];
+
+        self.send_request::<_, MergeStatus>(&url, query).await
+    }
+
+    // The merge status is checked first so that unmergeable requests fail with a
+    // descriptive error instead of a generic server response.
+    pub async fn merge_merge_request(
+        &self,
+        project_id: &str,
+        review_id: &str,
+        options: MergeOptions,
+    ) -> Result<MergeResult, Error> {
+        let status = self.get_merge_status(project_id, review_id).await?;
+        match status.state {
+            MergeState::Mergeable => {}
+            MergeState::Conflicts => return Err(Error::MergeConflicts(status.conflicts)),
+            MergeState::Merged => return Err(Error::AlreadyMerged(review_id.to_string())),
+            MergeState::Closed => return Err(Error::MergeRequestClosed(review_id.to_string())),
+            MergeState::Blocked | MergeState::Unknown => {
+                return Err(Error::MergeBlocked(
+                    status
+                        .reason
+                        .unwrap_or_else(|| format!("{:?}", status.state)),
+                ));
+            }
+        }
+
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/code-reviews/id:{review_id}/merge",
+            self.base_url
+        );
+
+        let response = self
+            .send_json_request::<_, MergeApiResponse>(
+                reqwest::Method::PUT,
+                &url,
+                MergeRequestBody::from(options),
+            )
+            .await?;
+
+        match (response.success, response.result) {
+            (true, Some(result)) => Ok(result),
+            (_, _) => Err(Error::MergeRejected(
+                response
+                    .message
+                    .unwrap_or_else(|| "no reason given".to_string()),
+            )),
+        }
+    }
+}
@@ -0,0 +1,471 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{ChannelIdentifier, Client, Error, Message};
+
+const MARKDOWN_SPECIAL_CHARACTERS: &str = "\\`*_[]()#+-!|~<>";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "className")]
+pub enum ChatMessage {
+    #[serde(rename = "ChatMessage.Text")]
+    Text {
+        text: String,
+        #[serde(default)]
+        markdown: bool,
+    },
+    #[serde(rename = "ChatMessage.Block")]
+    Block {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<MessageStyle>,
+        sections: Vec<MessageSection>,
+    },
+}
+
+impl ChatMessage {
+    pub fn text(text: &str) -> Self {
+        Self::Text {
+            text: text.to_string(),
+            markdown: false,
+        }
+    }
+
+    pub fn markdown(text: &str) -> Self {
+        Self::Text {
+            text: text.to_string(),
+            markdown: true,
+        }
+    }
+
+    pub fn block() -> Self {
+        Self::Block {
+            style: None,
+            sections: vec![],
+        }
+    }
+
+    // Text messages are turned into a block so that styles and sections can always be added.
+    #[must_use]
+    pub fn style(self, style: MessageStyle) -> Self {
+        match self.into_block() {
+            Self::Block { sections, .. } => Self::Block {
+                style: Some(style),
+                sections,
+            },
+            text => text,
+        }
+    }
+
+    #[must_use]
+    pub fn section(self, section: MessageSection) -> Self {
+        match self.into_block() {
+            Self::Block {
+                style,
+                mut sections,
+            } => {
+                sections.push(section);
+                Self::Block { style, sections }
+            }
+            text => text,
+        }
+    }
+
+    // Block text is always rendered as markdown, so plain text is escaped to keep its meaning.
+    fn into_block(self) -> Self {
+        match self {
+            Self::Text { text, markdown } => {
+                let content = if markdown {
+                    text
+                } else {
+                    escape_markdown(&text)
+                };
+                Self::Block {
+                    style: None,
+                    sections: vec![MessageSection::new().text(&content)],
+                }
+            }
+            block => block,
+        }
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        if MARKDOWN_SPECIAL_CHARACTERS.contains(character) {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
+}
+
+impl From<&str> for ChatMessage {
+    fn from(text: &str) -> Self {
+        Self::text(text)
+    }
+}
+
+impl From<String> for ChatMessage {
+    fn from(text: String) -> Self {
+        Self::Text {
+            text,
+            markdown: false,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MessageStyle {
+    Primary,
+    Secondary,
+    Success,
+    Error,
+    Warning,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(tag = "className", rename = "MessageSection")]
+pub struct MessageSection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    pub elements: Vec<MessageElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+}
+
+impl MessageSection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn header(mut self, header: &str) -> Self {
+        self.header = Some(header.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn footer(mut self, footer: &str) -> Self {
+        self.footer = Some(footer.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn text(self, content: &str) -> Self {
+        self.text_element(MessageText::new(content))
+    }
+
+    #[must_use]
+    pub fn text_element(mut self, text: MessageText) -> Self {
+        self.elements.push(MessageElement::Text(text));
+        self
+    }
+
+    #[must_use]
+    pub fn field(mut self, first: &str, second: &str) -> Self {
+        let field = MessageField {
+            first: first.to_string(),
+            second: second.to_string(),
+        };
+
+        match self.elements.last_mut() {
+            Some(MessageElement::Fields { fields }) => fields.push(field),
+            _ => self.elements.push(MessageElement::Fields {
+                fields: vec![field],
+            }),
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn button(mut self, button: MessageButton) -> Self {
+        match self.elements.last_mut() {
+            Some(MessageElement::ControlGroup { elements }) => elements.push(button),
+            _ => self.elements.push(MessageElement::ControlGroup {
+                elements: vec![button],
+            }),
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn divider(mut self) -> Self {
+        self.elements.push(MessageElement::Divider);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "className")]
+pub enum MessageElement {
+    #[serde(rename = "MessageText")]
+    Text(MessageText),
+    #[serde(rename = "MessageFields")]
+    Fields { fields: Vec<MessageField> },
+    #[serde(rename = "MessageControlGroup")]
+    ControlGroup { elements: Vec<MessageButton> },
+    #[serde(rename = "MessageDivider")]
+    Divider,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MessageText {
+    pub content: String,
+    pub accent: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<MessageTextSize>,
+}
+
+impl MessageText {
+    pub fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+            accent: false,
+            size: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MessageTextSize {
+    Small,
+    Regular,
+    Large,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "className", rename = "MessageField")]
+pub struct MessageField {
+    pub first: String,
+    pub second: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "className", rename = "MessageButton")]
+pub struct MessageButton {
+    pub text: String,
+    pub style: MessageButtonStyle,
+    pub action: MessageAction,
+}
+
+impl MessageButton {
+    pub fn url(text: &str, url: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            style: MessageButtonStyle::Secondary,
+            action: MessageAction::NavigateUrl {
+                url: url.to_string(),
+                with_back_url: false,
+                open_in_new_tab: true,
+            },
+        }
+    }
+
+    pub fn post_message(text: &str, action_id: &str, payload: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            style: MessageButtonStyle::Primary,
+            action: MessageAction::PostMessage {
+                action_id: action_id.to_string(),
+                payload: payload.to_string(),
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: MessageButtonStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MessageButtonStyle {
+    Primary,
+    Secondary,
+    Danger,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "className")]
+pub enum MessageAction {
+    #[serde(rename = "NavigateUrlAction", rename_all = "camelCase")]
+    NavigateUrl {
+        url: String,
+        with_back_url: bool,
+        open_in_new_tab: bool,
+    },
+    #[serde(rename = "PostMessageAction", rename_all = "camelCase")]
+    PostMessage { action_id: String, payload: String },
+}
+
+#[derive(Serialize)]
+struct SendMessageRequest {
+    channel: ChannelIdentifier,
+    content: ChatMessage,
+}
+
+impl Client {
+    pub async fn send_message(
+        &self,
+        channel: ChannelIdentifier,
+        content: impl Into<ChatMessage>,
+    ) -> Result<Message, Error> {
+        let url = format!("{}/api/http/chats/messages/send-message", self.base_url);
+
+        let body = SendMessageRequest {
+            channel,
+            content: content.into(),
+        };
+
+        self.send_json_request::<_, Message>(reqwest::Method::POST, &url, body)
+            .await
+    }
+
+    pub async fn send_direct_message(
+        &self,
+        profile_id: &str,
+        content: impl Into<ChatMessage>,
+    ) -> Result<Message, Error> {
+        self.send_message(ChannelIdentifier::Profile(profile_id.to_string()), content)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_text_message_serialization() {
+        assert_eq!(
+            serde_json::to_value(ChatMessage::markdown("**Build** failed")).unwrap(),
+            json!({
+                "className": "ChatMessage.Text",
+                "text": "**Build** failed",
+                "markdown": true
+            })
+        );
+    }
+
+    #[test]
+    fn test_block_message_serialization() {
+        let message = ChatMessage::block().style(MessageStyle::Error).section(
+            MessageSection::new()
+                .header("Build failed")
+                .text("Pipeline **#42** failed on `main`")
+                .field("Branch", "main")
+                .field("Commit", "a1b2c3d")
+                .divider()
+                .button(MessageButton::url(
+                    "Open build",
+                    "https://ci.example.com/42",
+                ))
+                .button(
+                    MessageButton::post_message("Retry", "retry", "42")
+                        .style(MessageButtonStyle::Danger),
+                )
+                .footer("CI"),
+        );
+
+        assert_eq!(
+            serde_json::to_value(message).unwrap(),
+            json!({
+                "className": "ChatMessage.Block",
+                "style": "ERROR",
+                "sections": [{
+                    "className": "MessageSection",
+                    "header": "Build failed",
+                    "elements": [
+                        {
+                            "className": "MessageText",
+                            "content": "Pipeline **#42** failed on `main`",
+                            "accent": false
+                        },
+                        {
+                            "className": "MessageFields",
+                            "fields": [
+                                { "className": "MessageField", "first": "Branch", "second": "main" },
+                                { "className": "MessageField", "first": "Commit", "second": "a1b2c3d" }
+                            ]
+                        },
+                        { "className": "MessageDivider" },
+                        {
+                            "className": "MessageControlGroup",
+                            "elements": [
+                                {
+                                    "className": "MessageButton",
+                                    "text": "Open build",
+                                    "style": "SECONDARY",
+                                    "action": {
+                                        "className": "NavigateUrlAction",
+                                        "url": "https://ci.example.com/42",
+                                        "withBackUrl": false,
+                                        "openInNewTab": true
+                                    }
+                                },
+                                {
+                                    "className": "MessageButton",
+                                    "text": "Retry",
+                                    "style": "DANGER",
+                                    "action": {
+                                        "className": "PostMessageAction",
+                                        "actionId": "retry",
+                                        "payload": "42"
+                                    }
+                                }
+                            ]
+                        }
+                    ],
+                    "footer": "CI"
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_text_message_turns_into_block_when_styled() {
+        let message = ChatMessage::text("Deployed").style(MessageStyle::Success);
+
+        let ChatMessage::Block { style, sections } = message else {
+            panic!("expected a block message");
+        };
+        assert_eq!(style, Some(MessageStyle::Success));
+        assert_eq!(sections.len(), 1);
+        assert_eq!(
+            sections[0].elements,
+            vec![MessageElement::Text(MessageText {
+                content: "Deployed".to_string(),
+                accent: false,
+                size: None,
+            })]
+        );
+    }
+
+    #[test]
+    fn test_styled_text_message_keeps_markdown_flag() {
+        let plain = ChatMessage::text("a*b* [link](x)").style(MessageStyle::Warning);
+        let markdown = ChatMessage::markdown("a*b*").style(MessageStyle::Warning);
+
+        let ChatMessage::Block { sections, .. } = plain else {
+            panic!("expected a block message");
+        };
+        assert_eq!(
+            sections[0].elements,
+            vec![MessageElement::Text(MessageText::new(
+                r"a\*b\* \[link\]\(x\)"
+            ))]
+        );
+        let ChatMessage::Block { sections, .. } = markdown else {
+            panic!("expected a block message");
+        };
+        assert_eq!(
+            sections[0].elements,
+            vec![MessageElement::Text(MessageText::new("a*b*"))]
+        );
+    }
+}
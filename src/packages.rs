@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, deserializers, encode_path_segment};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PackageRepository {
+    pub id: String,
+    pub name: String,
+    #[serde(
+        rename(deserialize = "type"),
+        deserialize_with = "deserializers::deserialize_package_type"
+    )]
+    pub package_type: PackageType,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub public: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageType {
+    Cargo,
+    Container,
+    Maven,
+    Npm,
+    Nuget,
+    Pypi,
+    Composer,
+    Files,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Package {
+    pub name: String,
+    #[serde(
+        rename(deserialize = "created"),
+        deserialize_with = "deserializers::deserialize_timestamp_millis"
+    )]
+    pub created_at: DateTime<Utc>,
+    #[serde(
+        rename(deserialize = "lastUpdated"),
+        deserialize_with = "deserializers::deserialize_optional_timestamp_millis",
+        default
+    )]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PackageVersion {
+    pub version: String,
+    #[serde(
+        rename(deserialize = "created"),
+        deserialize_with = "deserializers::deserialize_timestamp_millis"
+    )]
+    pub created_at: DateTime<Utc>,
+    #[serde(
+        rename(deserialize = "accessed"),
+        deserialize_with = "deserializers::deserialize_optional_timestamp_millis",
+        default
+    )]
+    pub accessed_at: Option<DateTime<Utc>>,
+    #[serde(rename(deserialize = "diskSize"), default)]
+    pub disk_size: u64,
+    #[serde(default)]
+    pub downloads: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PackageVersionDetails {
+    #[serde(flatten)]
+    pub version: PackageVersion,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct PackageRepositoriesApiResponse {
+    data: Vec<PackageRepository>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct PackagesApiResponse {
+    data: Vec<Package>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct PackageVersionsApiResponse {
+    data: Vec<PackageVersion>,
+}
+
+const PACKAGE_VERSION_FIELDS: &str = "version,created,accessed,diskSize,downloads";
+
+impl Client {
+    pub async fn get_package_repositories(
+        &self,
+        project_id: &str,
+    ) -> Result<Vec<PackageRepository>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/packages/repositories",
+            self.base_url
+        );
+
+        let query = HashMap::from([
+            (
+                "$fields",
+                "data(id,name,type(id),description,public),next,totalCount",
+            ),
+            ("$top", "100000"),
+        ]);
+
+        Ok(self
+            .send_request::<_, PackageRepositoriesApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_packages(
+        &self,
+        project_id: &str,
+        repository_id: &str,
+    ) -> Result<Vec<Package>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/packages/repositories/id:{repository_id}/packages",
+            self.base_url
+        );
+
+        let query = HashMap::from([
+            ("$fields", "data(name,created,lastUpdated),next,totalCount"),
+            ("$top", "100000"),
+        ]);
+
+        Ok(self
+            .send_request::<_, PackagesApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_package_versions(
+        &self,
+        project_id: &str,
+        repository_id: &str,
+        package_name: &str,
+    ) -> Result<Vec<PackageVersion>, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/packages/repositories/id:{repository_id}/packages/{}/versions",
+            self.base_url,
+            encode_path_segment(package_name)
+        );
+
+        let fields = format!("data({PACKAGE_VERSION_FIELDS}),next,totalCount");
+        let query = HashMap::from([("$fields", fields.as_str()), ("$top", "100000")]);
+
+        Ok(self
+            .send_request::<_, PackageVersionsApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_package_version(
+        &self,
+        project_id: &str,
+        repository_id: &str,
+        package_name: &str,
+        version: &str,
+    ) -> Result<PackageVersionDetails, Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/packages/repositories/id:{repository_id}/packages/{}/versions/{}",
+            self.base_url,
+            encode_path_segment(package_name),
+            encode_path_segment(version)
+        );
+
+        let fields = format!("{PACKAGE_VERSION_FIELDS},tags,metadata");
+        let query = HashMap::from([("$fields", fields.as_str())]);
+
+        self.send_request::<_, PackageVersionDetails>(&url, query)
+            .await
+    }
+
+    pub async fn delete_package_version(
+        &self,
+        project_id: &str,
+        repository_id: &str,
+        package_name: &str,
+        version: &str,
+    ) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/projects/id:{project_id}/packages/repositories/id:{repository_id}/packages/{}/versions/{}",
+            self.base_url,
+            encode_path_segment(package_name),
+            encode_path_segment(version)
+        );
+
+        self.send_request_without_response::<()>(reqwest::Method::DELETE, &url, None)
+            .await
+    }
+}
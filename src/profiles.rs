@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, Member, deserializers};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Profile {
+    pub id: String,
+    pub username: String,
+    #[serde(default)]
+    pub locations: Vec<ProfileLocation>,
+    #[serde(default)]
+    pub working_days: Vec<WorkingDays>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProfileLocation {
+    pub id: String,
+    pub location: Location,
+    #[serde(
+        deserialize_with = "deserializers::deserialize_optional_space_local_date",
+        default
+    )]
+    pub since: Option<NaiveDate>,
+    #[serde(
+        deserialize_with = "deserializers::deserialize_optional_space_local_date",
+        default
+    )]
+    pub till: Option<NaiveDate>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Location {
+    pub id: String,
+    pub name: String,
+    #[serde(
+        rename(deserialize = "timezone"),
+        deserialize_with = "deserializers::deserialize_optional_id",
+        default
+    )]
+    pub time_zone: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WorkingDays {
+    pub id: String,
+    #[serde(
+        rename(deserialize = "dateStart"),
+        deserialize_with = "deserializers::deserialize_optional_space_local_date",
+        default
+    )]
+    pub date_start: Option<NaiveDate>,
+    #[serde(
+        rename(deserialize = "dateEnd"),
+        deserialize_with = "deserializers::deserialize_optional_space_local_date",
+        default
+    )]
+    pub date_end: Option<NaiveDate>,
+    #[serde(
+        rename(deserialize = "workingDaysSpec"),
+        deserialize_with = "deserializers::deserialize_working_days_spec"
+    )]
+    pub days: Vec<WorkingDay>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WorkingDay {
+    pub day: Weekday,
+    pub working: bool,
+    #[serde(rename(deserialize = "timeIntervals"), default)]
+    pub intervals: Vec<WorkingInterval>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WorkingInterval {
+    #[serde(deserialize_with = "deserializers::deserialize_working_time")]
+    pub since: NaiveTime,
+    #[serde(deserialize_with = "deserializers::deserialize_working_time")]
+    pub until: NaiveTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkingWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WorkingDaysApiResponse {
+    data: Vec<WorkingDays>,
+}
+
+impl WorkingDays {
+    pub fn applies_to(&self, date: NaiveDate) -> bool {
+        self.date_start.is_none_or(|start| start <= date)
+            && self.date_end.is_none_or(|end| date <= end)
+    }
+}
+
+impl Profile {
+    pub fn location_on(&self, date: NaiveDate) -> Option<&Location> {
+        self.locations
+            .iter()
+            .find(|location| {
+                location.since.is_none_or(|since| since <= date)
+                    && location.till.is_none_or(|till| date <= till)
+            })
+            .map(|location| &location.location)
+    }
+
+    pub fn time_zone_on(&self, date: NaiveDate) -> Option<Tz> {
+        self.location_on(date)?.time_zone.as_deref()?.parse().ok()
+    }
+
+    // Working hours are defined in the profile's local time; profiles without a known
+    // time zone are treated as UTC.
+    pub fn working_windows_on(&self, date: NaiveDate) -> Vec<WorkingWindow> {
+        let time_zone = self.time_zone_on(date).unwrap_or(Tz::UTC);
+        let Some(day) = self
+            .working_days
+            .iter()
+            .find(|working_days| working_days.applies_to(date))
+            .and_then(|working_days| {
+                working_days
+                    .days
+                    .iter()
+                    .find(|day| day.day == date.weekday())
+            })
+        else {
+            return vec![];
+        };
+
+        if !day.working {
+            return vec![];
+        }
+
+        day.intervals
+            .iter()
+            .filter_map(|interval| {
+                let end_date = if interval.until <= interval.since {
+                    date.succ_opt()?
+                } else {
+                    date
+                };
+                let start = time_zone
+                    .from_local_datetime(&date.and_time(interval.since))
+                    .earliest()?;
+                let end = time_zone
+                    .from_local_datetime(&end_date.and_time(interval.until))
+                    .earliest()?;
+
+                Some(WorkingWindow {
+                    start: start.with_timezone(&Utc),
+                    end: end.with_timezone(&Utc),
+                })
+            })
+            .collect()
+    }
+}
+
+pub fn overlapping_working_hours(profiles: &[Profile], date: NaiveDate) -> Vec<WorkingWindow> {
+    let Some((first, rest)) = profiles.split_first() else {
+        return vec![];
+    };
+
+    rest.iter()
+        .fold(first.working_windows_on(date), |overlap, profile| {
+            let windows = profile.working_windows_on(date);
+            overlap
+                .iter()
+                .flat_map(|left| {
+                    windows.iter().filter_map(|right| {
+                        let start = left.start.max(right.start);
+                        let end = left.end.min(right.end);
+                        (end - start > TimeDelta::zero()).then_some(WorkingWindow { start, end })
+                    })
+                })
+                .collect()
+        })
+}
+
+impl Client {
+    pub async fn get_profile(&self, profile_id: &str) -> Result<Profile, Error> {
+        let url = format!(
+            "{}/api/http/team-directory/profiles/id:{profile_id}",
+            self.base_url
+        );
+
+        let query = HashMap::from([(
+            "$fields",
+            "id,username,locations(id,location(id,name,timezone(id)),since,till)",
+        )]);
+
+        let mut profile = self.send_request::<_, Profile>(&url, query).await?;
+        profile.working_days = self.get_profile_working_days(profile_id).await?;
+
+        Ok(profile)
+    }
+
+    pub async fn get_profile_working_days(
+        &self,
+        profile_id: &str,
+    ) -> Result<Vec<WorkingDays>, Error> {
+        let url = format!(
+            "{}/api/http/team-directory/profiles/id:{profile_id}/working-days",
+            self.base_url
+        );
+
+        let query = HashMap::from([(
+            "$fields",
+            "data(id,dateStart,dateEnd,workingDaysSpec(days(day,working,timeIntervals(since,until)))),next,totalCount",
+        )]);
+
+        Ok(self
+            .send_request::<_, WorkingDaysApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_overlapping_working_hours(
+        &self,
+        members: &[Member],
+        date: NaiveDate,
+    ) -> Result<Vec<WorkingWindow>, Error> {
+        let mut profiles = Vec::with_capacity(members.len());
+        for member in members {
+            profiles.push(self.get_profile(&member.id).await?);
+        }
+
+        Ok(overlapping_working_hours(&profiles, date))
+    }
+}
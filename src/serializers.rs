@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use serde::{Serialize, Serializer};
+
+pub fn serialize_profile_identifier<S>(profile_id: &str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format!("id:{profile_id}"))
+}
+
+pub fn serialize_optional_profile_identifier<S>(
+    profile_id: &Option<String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match profile_id {
+        Some(id) => serializer.serialize_some(&format!("id:{id}")),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub fn serialize_reviewers<S>(profile_ids: &[String], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(
+        profile_ids
+            .iter()
+            .map(|id| std::collections::HashMap::from([("profileId", format!("id:{id}"))])),
+    )
+}
+
+pub fn serialize_identifier<S>(id: &str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format!("id:{id}"))
+}
+
+pub fn serialize_identifiers<S>(ids: &[String], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(ids.iter().map(|id| format!("id:{id}")))
+}
+
+pub fn serialize_optional_identifiers<S>(
+    ids: &Option<Vec<String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match ids {
+        Some(ids) => serialize_identifiers(ids, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub fn serialize_project_key<S>(key: &str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_map([("key", key)])
+}
+
+pub fn folder_identifier(folder_id: Option<&str>) -> String {
+    folder_id.map_or("root".to_string(), |id| format!("id:{id}"))
+}
+
+pub fn serialize_folder_identifier<S>(
+    folder_id: &Option<String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&folder_identifier(folder_id.as_deref()))
+}
+
+pub fn serialize_text_body_create<S>(text: &str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    HashMap::from([("className", "TextDocumentBodyCreateIn"), ("text", text)]).serialize(serializer)
+}
+
+pub fn serialize_text_body_update<S>(
+    text: &Option<String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match text {
+        Some(text) => HashMap::from([("className", "TextDocumentBodyUpdateIn"), ("text", text)])
+            .serialize(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub fn serialize_time_zone<S>(time_zone: &Option<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct TimeZone<'a> {
+        id: &'a str,
+    }
+
+    match time_zone {
+        Some(id) => serializer.serialize_some(&TimeZone { id }),
+        None => serializer.serialize_none(),
+    }
+}
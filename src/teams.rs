@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, Member, deserializers, serializers};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Team {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(
+        rename(deserialize = "parent"),
+        deserialize_with = "deserializers::deserialize_optional_id",
+        default
+    )]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TeamNode {
+    pub team: Team,
+    pub children: Vec<TeamNode>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Membership {
+    pub id: String,
+    pub member: Member,
+    pub team: Team,
+    #[serde(deserialize_with = "deserializers::deserialize_optional_name", default)]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub lead: bool,
+    #[serde(default)]
+    pub manager: Option<Member>,
+    #[serde(
+        deserialize_with = "deserializers::deserialize_optional_space_date",
+        default
+    )]
+    pub since: Option<DateTime<Utc>>,
+    #[serde(
+        deserialize_with = "deserializers::deserialize_optional_space_date",
+        default
+    )]
+    pub till: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TeamsApiResponse {
+    data: Vec<Team>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct MembershipsApiResponse {
+    data: Vec<Membership>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TeamsQuery {
+    pub query: Option<String>,
+    #[serde(rename(serialize = "withArchived"))]
+    pub with_archived: bool,
+    #[serde(rename(serialize = "$top"))]
+    pub top: usize,
+    #[serde(rename(serialize = "$skip"))]
+    pub skip: usize,
+    #[serde(rename(serialize = "$fields"))]
+    pub fields: String,
+}
+
+impl TeamsQuery {
+    pub fn new() -> Self {
+        Self {
+            query: None,
+            with_archived: false,
+            top: 100000,
+            skip: 0,
+            fields: "data(id,name,description,archived,parent(id)),next,totalCount".to_string(),
+        }
+    }
+}
+
+impl Default for TeamsQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MembershipsQuery {
+    #[serde(rename(serialize = "teams"))]
+    pub team_id: Option<String>,
+    #[serde(
+        rename(serialize = "profiles"),
+        serialize_with = "serializers::serialize_optional_profile_identifier"
+    )]
+    pub profile_id: Option<String>,
+    #[serde(rename(serialize = "withArchived"))]
+    pub with_archived: bool,
+    #[serde(rename(serialize = "$top"))]
+    pub top: usize,
+    #[serde(rename(serialize = "$skip"))]
+    pub skip: usize,
+    #[serde(rename(serialize = "$fields"))]
+    pub fields: String,
+}
+
+impl MembershipsQuery {
+    pub fn new() -> Self {
+        Self {
+            team_id: None,
+            profile_id: None,
+            with_archived: false,
+            top: 100000,
+            skip: 0,
+            fields: "data(id,member(id,username),team(id,name,description,archived,parent(id)),role(name),lead,manager(id,username),since,till),next,totalCount".to_string(),
+        }
+    }
+
+    pub fn for_team(team_id: &str) -> Self {
+        Self {
+            team_id: Some(team_id.to_string()),
+            ..Self::new()
+        }
+    }
+
+    pub fn for_profile(profile_id: &str) -> Self {
+        Self {
+            profile_id: Some(profile_id.to_string()),
+            ..Self::new()
+        }
+    }
+}
+
+impl Default for MembershipsQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn build_team_tree(teams: Vec<Team>) -> Vec<TeamNode> {
+    let known_ids: Vec<String> = teams.iter().map(|team| team.id.clone()).collect();
+    let mut children_by_parent: HashMap<Option<String>, Vec<Team>> = HashMap::new();
+    for team in teams {
+        let parent = team
+            .parent_id
+            .clone()
+            .filter(|parent_id| known_ids.contains(parent_id));
+        children_by_parent.entry(parent).or_default().push(team);
+    }
+
+    fn attach(team: Team, children_by_parent: &mut HashMap<Option<String>, Vec<Team>>) -> TeamNode {
+        let children = children_by_parent
+            .remove(&Some(team.id.clone()))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|child| attach(child, children_by_parent))
+            .collect();
+
+        TeamNode { team, children }
+    }
+
+    children_by_parent
+        .remove(&None)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|root| attach(root, &mut children_by_parent))
+        .collect()
+}
+
+impl Client {
+    pub async fn get_teams(&self, query: TeamsQuery) -> Result<Vec<Team>, Error> {
+        let url = format!("{}/api/http/team-directory/teams", self.base_url);
+
+        Ok(self
+            .send_request::<_, TeamsApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_team_tree(&self, query: TeamsQuery) -> Result<Vec<TeamNode>, Error> {
+        Ok(build_team_tree(self.get_teams(query).await?))
+    }
+
+    pub async fn get_memberships(&self, query: MembershipsQuery) -> Result<Vec<Membership>, Error> {
+        let url = format!("{}/api/http/team-directory/memberships", self.base_url);
+
+        Ok(self
+            .send_request::<_, MembershipsApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn get_team_members(&self, team_id: &str) -> Result<Vec<Membership>, Error> {
+        self.get_memberships(MembershipsQuery::for_team(team_id))
+            .await
+    }
+
+    pub async fn get_team_managers(&self, team_id: &str) -> Result<Vec<Membership>, Error> {
+        Ok(self
+            .get_team_members(team_id)
+            .await?
+            .into_iter()
+            .filter(|membership| membership.lead)
+            .collect())
+    }
+
+    pub async fn get_profile_memberships(
+        &self,
+        profile_id: &str,
+    ) -> Result<Vec<Membership>, Error> {
+        self.get_memberships(MembershipsQuery::for_profile(profile_id))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn team(id: &str, parent_id: Option<&str>) -> Team {
+        Team {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: None,
+            archived: false,
+            parent_id: parent_id.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_build_team_tree() {
+        let tree = build_team_tree(vec![
+            team("backend", Some("engineering")),
+            team("engineering", Some("root")),
+            team("root", None),
+            team("frontend", Some("engineering")),
+            team("sales", Some("root")),
+        ]);
+
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].team.id, "root");
+        assert_eq!(tree[0].children.len(), 2);
+        assert_eq!(tree[0].children[0].team.id, "engineering");
+        assert_eq!(tree[0].children[1].team.id, "sales");
+
+        let engineering = &tree[0].children[0];
+        let children: Vec<&str> = engineering
+            .children
+            .iter()
+            .map(|node| node.team.id.as_str())
+            .collect();
+        assert_eq!(children, vec!["backend", "frontend"]);
+    }
+
+    #[test]
+    fn test_build_team_tree_with_unknown_parent_becomes_root() {
+        let tree = build_team_tree(vec![team("orphan", Some("missing")), team("root", None)]);
+
+        let roots: Vec<&str> = tree.iter().map(|node| node.team.id.as_str()).collect();
+        assert_eq!(roots, vec!["orphan", "root"]);
+    }
+}
@@ -0,0 +1,151 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, Error, deserializers};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TodoItem {
+    pub id: String,
+    #[serde(
+        rename(deserialize = "content"),
+        deserialize_with = "deserializers::deserialize_text_content"
+    )]
+    pub text: String,
+    pub open: bool,
+    #[serde(
+        rename(deserialize = "dueDate"),
+        deserialize_with = "deserializers::deserialize_optional_space_local_date",
+        default
+    )]
+    pub due_date: Option<NaiveDate>,
+}
+
+impl TodoItem {
+    pub fn is_done(&self) -> bool {
+        !self.open
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TodoItemsApiResponse {
+    data: Vec<TodoItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TodoItemsQuery {
+    pub open: Option<bool>,
+    pub from: Option<NaiveDate>,
+    pub till: Option<NaiveDate>,
+    #[serde(rename(serialize = "$top"))]
+    pub top: usize,
+    #[serde(rename(serialize = "$skip"))]
+    pub skip: usize,
+    #[serde(rename(serialize = "$fields"))]
+    pub fields: String,
+}
+
+impl TodoItemsQuery {
+    pub fn new() -> Self {
+        Self {
+            open: None,
+            from: None,
+            till: None,
+            top: 100000,
+            skip: 0,
+            fields: "data(id,content(text),open,dueDate),next,totalCount".to_string(),
+        }
+    }
+
+    pub fn open() -> Self {
+        Self {
+            open: Some(true),
+            ..Self::new()
+        }
+    }
+
+    pub fn done() -> Self {
+        Self {
+            open: Some(false),
+            ..Self::new()
+        }
+    }
+}
+
+impl Default for TodoItemsQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewTodoItem {
+    pub text: String,
+    #[serde(rename(serialize = "dueDate"), skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<NaiveDate>,
+}
+
+impl NewTodoItem {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            due_date: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TodoItemUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(rename(serialize = "dueDate"), skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open: Option<bool>,
+}
+
+impl Client {
+    pub async fn get_todo_items(&self, query: TodoItemsQuery) -> Result<Vec<TodoItem>, Error> {
+        let url = format!("{}/api/http/todo", self.base_url);
+
+        Ok(self
+            .send_request::<_, TodoItemsApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    pub async fn create_todo_item(&self, item: NewTodoItem) -> Result<TodoItem, Error> {
+        let url = format!("{}/api/http/todo", self.base_url);
+
+        self.send_json_request::<_, TodoItem>(reqwest::Method::POST, &url, item)
+            .await
+    }
+
+    pub async fn update_todo_item(
+        &self,
+        item_id: &str,
+        update: TodoItemUpdate,
+    ) -> Result<(), Error> {
+        let url = format!("{}/api/http/todo/{item_id}", self.base_url);
+
+        self.send_request_without_response(reqwest::Method::PATCH, &url, Some(update))
+            .await
+    }
+
+    pub async fn complete_todo_item(&self, item_id: &str) -> Result<(), Error> {
+        self.update_todo_item(
+            item_id,
+            TodoItemUpdate {
+                open: Some(false),
+                ..TodoItemUpdate::default()
+            },
+        )
+        .await
+    }
+
+    pub async fn delete_todo_item(&self, item_id: &str) -> Result<(), Error> {
+        let url = format!("{}/api/http/todo/{item_id}", self.base_url);
+
+        self.send_request_without_response::<()>(reqwest::Method::DELETE, &url, None)
+            .await
+    }
+}
@@ -0,0 +1,422 @@
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD as BASE64, URL_SAFE_NO_PAD as BASE64_URL};
+use chrono::{DateTime, TimeDelta, Utc};
+use hmac::{Hmac, Mac};
+use rsa::pkcs1v15::{Signature, VerifyingKey};
+use rsa::signature::Verifier;
+use rsa::{BigUint, RsaPublicKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Sha512};
+
+use crate::{Client, Error, Issue, Member, Message, deserializers};
+
+pub const SIGNATURE_HEADER: &str = "X-Space-Signature";
+pub const PUBLIC_KEY_SIGNATURE_HEADER: &str = "X-Space-Public-Key-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Space-Timestamp";
+
+const DEFAULT_TOLERANCE_SECONDS: i64 = 300;
+
+#[derive(Debug, Clone)]
+enum VerificationKey {
+    Signing(Vec<u8>),
+    // Space signs with SHA512withRSA and may rotate between the keys of its key set.
+    Public(Vec<VerifyingKey<Sha512>>),
+}
+
+#[derive(Deserialize)]
+struct JsonWebKeySet {
+    keys: Vec<JsonWebKey>,
+}
+
+#[derive(Deserialize)]
+struct JsonWebKey {
+    kty: String,
+    #[serde(default)]
+    n: String,
+    #[serde(default)]
+    e: String,
+}
+
+impl JsonWebKey {
+    fn into_verifying_key(self) -> Result<VerifyingKey<Sha512>, Error> {
+        let decode = |value: &str| {
+            BASE64_URL
+                .decode(value.trim_end_matches('='))
+                .map(|bytes| BigUint::from_bytes_be(&bytes))
+                .map_err(|_| Error::WebhookSignature("public key is not valid base64".to_string()))
+        };
+
+        RsaPublicKey::new(decode(&self.n)?, decode(&self.e)?)
+            .map(VerifyingKey::new)
+            .map_err(|error| Error::WebhookSignature(error.to_string()))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookVerifier {
+    key: VerificationKey,
+    tolerance: TimeDelta,
+}
+
+impl WebhookVerifier {
+    pub fn signing_key(signing_key: &str) -> Self {
+        Self {
+            key: VerificationKey::Signing(signing_key.as_bytes().to_vec()),
+            tolerance: TimeDelta::seconds(DEFAULT_TOLERANCE_SECONDS),
+        }
+    }
+
+    // Takes the JWKS document returned by `Client::get_space_public_keys`.
+    pub fn public_keys(key_set: &str) -> Result<Self, Error> {
+        let key_set: JsonWebKeySet = serde_json::from_str(key_set)
+            .map_err(|error| Error::WebhookSignature(format!("invalid key set: {error}")))?;
+        let keys = key_set
+            .keys
+            .into_iter()
+            .filter(|key| key.kty == "RSA")
+            .map(JsonWebKey::into_verifying_key)
+            .collect::<Result<Vec<_>, _>>()?;
+        if keys.is_empty() {
+            return Err(Error::WebhookSignature(
+                "key set contains no RSA keys".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            key: VerificationKey::Public(keys),
+            tolerance: TimeDelta::seconds(DEFAULT_TOLERANCE_SECONDS),
+        })
+    }
+
+    #[must_use]
+    pub fn tolerance(mut self, tolerance: TimeDelta) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    // `header` looks up a request header by name, which keeps the verifier independent
+    // of the web framework receiving the request.
+    pub fn verify<'a>(
+        &self,
+        header: impl Fn(&str) -> Option<&'a str>,
+        body: &[u8],
+        now: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        let timestamp = header(TIMESTAMP_HEADER)
+            .ok_or_else(|| missing_header(TIMESTAMP_HEADER))?
+            .trim();
+        let sent_at = timestamp
+            .parse::<i64>()
+            .ok()
+            .and_then(DateTime::from_timestamp_millis)
+            .ok_or_else(|| Error::WebhookSignature(format!("invalid timestamp {timestamp}")))?;
+        if (now - sent_at).abs() > self.tolerance {
+            return Err(Error::WebhookSignature(format!(
+                "timestamp {timestamp} is outside of the allowed window"
+            )));
+        }
+
+        let mut signed = format!("{timestamp}:").into_bytes();
+        signed.extend_from_slice(body);
+
+        match &self.key {
+            VerificationKey::Signing(key) => {
+                let signature =
+                    header(SIGNATURE_HEADER).ok_or_else(|| missing_header(SIGNATURE_HEADER))?;
+                let signature = hex::decode(signature.trim()).map_err(|_| {
+                    Error::WebhookSignature("signature is not valid hex".to_string())
+                })?;
+
+                let mut mac = Hmac::<Sha256>::new_from_slice(key)
+                    .map_err(|error| Error::WebhookSignature(error.to_string()))?;
+                mac.update(&signed);
+                mac.verify_slice(&signature)
+                    .map_err(|_| Error::WebhookSignature("signature does not match".to_string()))
+            }
+            VerificationKey::Public(keys) => {
+                let signature = header(PUBLIC_KEY_SIGNATURE_HEADER)
+                    .ok_or_else(|| missing_header(PUBLIC_KEY_SIGNATURE_HEADER))?;
+                let signature = BASE64
+                    .decode(signature.trim())
+                    .ok()
+                    .and_then(|bytes| Signature::try_from(bytes.as_slice()).ok())
+                    .ok_or_else(|| Error::WebhookSignature("signature is malformed".to_string()))?;
+
+                if keys
+                    .iter()
+                    .any(|key| key.verify(&signed, &signature).is_ok())
+                {
+                    Ok(())
+                } else {
+                    Err(Error::WebhookSignature(
+                        "signature does not match".to_string(),
+                    ))
+                }
+            }
+        }
+    }
+
+    pub fn verify_and_parse<'a>(
+        &self,
+        header: impl Fn(&str) -> Option<&'a str>,
+        body: &[u8],
+        now: DateTime<Utc>,
+    ) -> Result<WebhookPayload, Error> {
+        self.verify(header, body, now)?;
+        parse_webhook_payload(body)
+    }
+}
+
+impl Client {
+    // Returns the JWKS document with the keys Space signs application requests with.
+    pub async fn get_space_public_keys(&self) -> Result<String, Error> {
+        let url = format!("{}/api/http/applications/public-keys", self.base_url);
+
+        self.send_request::<_, String>(&url, ()).await
+    }
+}
+
+fn missing_header(name: &str) -> Error {
+    Error::WebhookSignature(format!("missing {name} header"))
+}
+
+pub fn parse_webhook_payload(body: &[u8]) -> Result<WebhookPayload, Error> {
+    serde_json::from_slice(body).map_err(Error::WebhookPayload)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WebhookPayload {
+    #[serde(rename(deserialize = "clientId"))]
+    pub client_id: String,
+    #[serde(rename(deserialize = "webhookId"))]
+    pub webhook_id: String,
+    #[serde(rename(deserialize = "payload"))]
+    pub event: WebhookEvent,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "RawWebhookEvent")]
+pub enum WebhookEvent {
+    IssueCreated {
+        issue: Issue,
+        actor: Member,
+        at: DateTime<Utc>,
+    },
+    IssueUpdated {
+        issue: Issue,
+        actor: Member,
+        at: DateTime<Utc>,
+    },
+    IssueDeleted {
+        issue: Issue,
+        actor: Member,
+        at: DateTime<Utc>,
+    },
+    MessagePosted {
+        channel_id: String,
+        message: Message,
+        at: DateTime<Utc>,
+    },
+    MemberJoined {
+        member: Member,
+        at: DateTime<Utc>,
+    },
+    MemberLeft {
+        member: Member,
+        at: DateTime<Utc>,
+    },
+    Unknown,
+}
+
+#[derive(Deserialize)]
+struct EventMeta {
+    #[serde(deserialize_with = "deserializers::deserialize_created_by")]
+    principal: Member,
+    #[serde(deserialize_with = "deserializers::deserialize_space_date")]
+    timestamp: DateTime<Utc>,
+    #[serde(default)]
+    method: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "className")]
+enum RawWebhookEvent {
+    #[serde(rename = "IssueWebhookEvent")]
+    Issue { meta: EventMeta, issue: Issue },
+    #[serde(rename = "ChatMessageCreatedEvent")]
+    Message {
+        meta: EventMeta,
+        #[serde(rename = "channelId")]
+        channel_id: String,
+        message: Message,
+    },
+    #[serde(rename = "ProfileOrganizationEvent")]
+    Profile {
+        meta: EventMeta,
+        profile: Member,
+        joined: bool,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<RawWebhookEvent> for WebhookEvent {
+    fn from(event: RawWebhookEvent) -> Self {
+        match event {
+            RawWebhookEvent::Issue { meta, issue } => {
+                let (actor, at) = (meta.principal, meta.timestamp);
+                match meta.method.as_deref() {
+                    Some("Created") => Self::IssueCreated { issue, actor, at },
+                    Some("Deleted") => Self::IssueDeleted { issue, actor, at },
+                    _ => Self::IssueUpdated { issue, actor, at },
+                }
+            }
+            RawWebhookEvent::Message {
+                meta,
+                channel_id,
+                message,
+            } => Self::MessagePosted {
+                channel_id,
+                message,
+                at: meta.timestamp,
+            },
+            RawWebhookEvent::Profile {
+                meta,
+                profile,
+                joined,
+            } => {
+                if joined {
+                    Self::MemberJoined {
+                        member: profile,
+                        at: meta.timestamp,
+                    }
+                } else {
+                    Self::MemberLeft {
+                        member: profile,
+                        at: meta.timestamp,
+                    }
+                }
+            }
+            RawWebhookEvent::Unknown => Self::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsa::RsaPrivateKey;
+    use rsa::pkcs1::DecodeRsaPrivateKey;
+    use rsa::pkcs1v15::SigningKey;
+    use rsa::signature::{SignatureEncoding, Signer};
+    use rsa::traits::PublicKeyParts;
+
+    const BODY: &[u8] = br#"{"className":"WebhookRequestPayload"}"#;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(1_749_038_400_000).unwrap()
+    }
+
+    fn hmac_signature(key: &str, timestamp: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key.as_bytes()).unwrap();
+        mac.update(format!("{timestamp}:").as_bytes());
+        mac.update(BODY);
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    #[test]
+    fn test_verify_signing_key() {
+        let signature = hmac_signature("secret", "1749038400000");
+        let header = |name: &str| match name {
+            TIMESTAMP_HEADER => Some("1749038400000"),
+            SIGNATURE_HEADER => Some(signature.as_str()),
+            _ => None,
+        };
+
+        let verifier = WebhookVerifier::signing_key("secret");
+
+        assert!(verifier.verify(header, BODY, now()).is_ok());
+        assert!(
+            WebhookVerifier::signing_key("other")
+                .verify(header, BODY, now())
+                .is_err()
+        );
+        assert!(verifier.verify(header, b"{}", now()).is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_stale_timestamp() {
+        let signature = hmac_signature("secret", "1749038400000");
+        let header = |name: &str| match name {
+            TIMESTAMP_HEADER => Some("1749038400000"),
+            SIGNATURE_HEADER => Some(signature.as_str()),
+            _ => None,
+        };
+
+        let later = now() + TimeDelta::minutes(10);
+        let verifier = WebhookVerifier::signing_key("secret");
+
+        assert!(verifier.verify(header, BODY, later).is_err());
+        assert!(
+            verifier
+                .tolerance(TimeDelta::minutes(15))
+                .verify(header, BODY, later)
+                .is_ok()
+        );
+    }
+
+    fn key_set(key: &RsaPrivateKey) -> String {
+        let public_key = key.to_public_key();
+        serde_json::json!({
+            "keys": [
+                { "kty": "EC", "crv": "P-256", "x": "f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU" },
+                {
+                    "kty": "RSA",
+                    "kid": "space",
+                    "n": BASE64_URL.encode(public_key.n().to_bytes_be()),
+                    "e": BASE64_URL.encode(public_key.e().to_bytes_be())
+                }
+            ]
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_verify_public_key() {
+        let private_key = RsaPrivateKey::from_pkcs1_pem(include_str!(
+            "../tests/fixtures/webhook_signing_key.pem"
+        ))
+        .unwrap();
+        let mut signed = b"1749038400000:".to_vec();
+        signed.extend_from_slice(BODY);
+        let signature = SigningKey::<Sha512>::new(private_key.clone()).sign(&signed);
+        let signature = BASE64.encode(signature.to_bytes());
+        let header = |name: &str| match name {
+            TIMESTAMP_HEADER => Some("1749038400000"),
+            PUBLIC_KEY_SIGNATURE_HEADER => Some(signature.as_str()),
+            _ => None,
+        };
+
+        let verifier = WebhookVerifier::public_keys(&key_set(&private_key)).unwrap();
+
+        assert!(verifier.verify(header, BODY, now()).is_ok());
+        assert!(verifier.verify(header, b"{}", now()).is_err());
+    }
+
+    #[test]
+    fn test_public_keys_require_an_rsa_key() {
+        let result = WebhookVerifier::public_keys(r#"{"keys":[{"kty":"EC"}]}"#);
+
+        assert!(matches!(result, Err(Error::WebhookSignature(_))));
+    }
+
+    #[test]
+    fn test_verify_missing_headers() {
+        let verifier = WebhookVerifier::signing_key("secret");
+
+        assert!(matches!(
+            verifier.verify(|_| None, BODY, now()),
+            Err(Error::WebhookSignature(_))
+        ));
+    }
+}
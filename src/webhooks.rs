@@ -0,0 +1,528 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ApplicationIdentifier, ChannelIdentifier, Client, Error, deserializers, encode_path_segment,
+    serializers,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Webhook {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub enabled: bool,
+    #[serde(
+        rename(deserialize = "endpoint"),
+        deserialize_with = "deserializers::deserialize_endpoint_url"
+    )]
+    pub url: String,
+    #[serde(skip_deserializing)]
+    pub subscriptions: Vec<Subscription>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "SubscriptionRecord")]
+pub struct Subscription {
+    pub id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub events: Vec<WebhookEventType>,
+    pub filters: Vec<EventFilter>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebhookEventType {
+    #[serde(rename = "Issue.Created")]
+    IssueCreated,
+    #[serde(rename = "Issue.Updated")]
+    IssueUpdated,
+    #[serde(rename = "Issue.Deleted")]
+    IssueDeleted,
+    #[serde(rename = "Chat.Message.Created")]
+    MessagePosted,
+    #[serde(rename = "Chat.Message.Updated")]
+    MessageUpdated,
+    #[serde(rename = "CodeReview.Created")]
+    CodeReviewCreated,
+    #[serde(rename = "CodeReview.Updated")]
+    CodeReviewUpdated,
+    #[serde(rename = "CodeReview.Participant.Changed")]
+    CodeReviewParticipantChanged,
+    #[serde(other)]
+    Unknown,
+}
+
+impl WebhookEventType {
+    pub fn subject(self) -> Option<&'static str> {
+        match self {
+            Self::IssueCreated | Self::IssueUpdated | Self::IssueDeleted => Some("Issue"),
+            Self::MessagePosted | Self::MessageUpdated => Some("Chat"),
+            Self::CodeReviewCreated
+            | Self::CodeReviewUpdated
+            | Self::CodeReviewParticipantChanged => Some("CodeReview"),
+            Self::Unknown => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "className")]
+pub enum EventFilter {
+    #[serde(rename = "ProjectFilterIn")]
+    Project {
+        #[serde(
+            rename = "project",
+            serialize_with = "serializers::serialize_identifier",
+            deserialize_with = "deserializers::deserialize_identifier"
+        )]
+        project_id: String,
+    },
+    #[serde(rename = "ChatChannelFilterIn")]
+    Channel { channel: ChannelIdentifier },
+    #[serde(rename = "RepositoryFilterIn")]
+    Repository {
+        #[serde(
+            rename = "project",
+            serialize_with = "serializers::serialize_identifier",
+            deserialize_with = "deserializers::deserialize_identifier"
+        )]
+        project_id: String,
+        repository: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewWebhook {
+    pub name: String,
+    pub description: Option<String>,
+    pub url: String,
+    pub enabled: bool,
+    pub verify_ssl: bool,
+}
+
+impl NewWebhook {
+    pub fn new(name: &str, url: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            description: None,
+            url: url.to_string(),
+            enabled: true,
+            verify_ssl: true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct WebhookUpdate {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub verify_ssl: Option<bool>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewSubscription {
+    pub name: String,
+    pub events: Vec<WebhookEventType>,
+    pub filters: Vec<EventFilter>,
+}
+
+impl NewSubscription {
+    pub fn new(name: &str, events: &[WebhookEventType]) -> Self {
+        Self {
+            name: name.to_string(),
+            events: events.to_vec(),
+            filters: vec![],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SubscriptionUpdate {
+    pub name: Option<String>,
+    pub enabled: Option<bool>,
+    pub events: Option<Vec<WebhookEventType>>,
+    pub filters: Option<Vec<EventFilter>>,
+}
+
+#[derive(Deserialize)]
+struct SubscriptionRecord {
+    id: String,
+    name: String,
+    enabled: bool,
+    subscription: SubscriptionDefinition,
+}
+
+impl From<SubscriptionRecord> for Subscription {
+    fn from(record: SubscriptionRecord) -> Self {
+        Self {
+            id: record.id,
+            name: record.name,
+            enabled: record.enabled,
+            events: record.subscription.event_types,
+            filters: record.subscription.filters,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SubscriptionDefinition {
+    subject_code: String,
+    #[serde(default)]
+    filters: Vec<EventFilter>,
+    #[serde(rename = "eventTypeCodes")]
+    event_types: Vec<WebhookEventType>,
+}
+
+impl SubscriptionDefinition {
+    // Space subscriptions are scoped to a single subject, so events of different
+    // subjects have to be registered as separate subscriptions.
+    fn new(events: Vec<WebhookEventType>, filters: Vec<EventFilter>) -> Result<Self, Error> {
+        if events.contains(&WebhookEventType::Unknown) {
+            return Err(Error::InvalidSubscription(
+                "unknown event types cannot be subscribed to".to_string(),
+            ));
+        }
+        if filters.contains(&EventFilter::Unknown) {
+            return Err(Error::InvalidSubscription(
+                "unknown filters cannot be sent back to Space".to_string(),
+            ));
+        }
+
+        let Some(subject) = events.first().and_then(|event| event.subject()) else {
+            return Err(Error::InvalidSubscription(
+                "at least one event type is required".to_string(),
+            ));
+        };
+        if let Some(event) = events.iter().find(|event| event.subject() != Some(subject)) {
+            return Err(Error::InvalidSubscription(format!(
+                "{event:?} does not belong to the {subject} subject"
+            )));
+        }
+
+        Ok(Self {
+            subject_code: subject.to_string(),
+            filters,
+            event_types: events,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct WebhookWithSubscriptions {
+    webhook: Webhook,
+    #[serde(default)]
+    subscriptions: Vec<Subscription>,
+}
+
+#[derive(Deserialize)]
+struct WebhooksApiResponse {
+    data: Vec<WebhookWithSubscriptions>,
+}
+
+#[derive(Deserialize)]
+struct SubscriptionsApiResponse {
+    data: Vec<Subscription>,
+}
+
+#[derive(Serialize)]
+struct WebhookEndpoint {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(rename = "sslVerification", skip_serializing_if = "Option::is_none")]
+    ssl_verification: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct WebhookRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    endpoint: Option<WebhookEndpoint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct SubscriptionRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscription: Option<SubscriptionDefinition>,
+}
+
+const WEBHOOK_FIELDS: &str = "id,name,description,enabled,endpoint(url)";
+const SUBSCRIPTION_FIELDS: &str =
+    "id,name,enabled,subscription(subjectCode,filters,eventTypeCodes)";
+
+impl Client {
+    pub async fn create_webhook(
+        &self,
+        application: &ApplicationIdentifier,
+        webhook: NewWebhook,
+    ) -> Result<Webhook, Error> {
+        let url = format!(
+            "{}/api/http/applications/{application}/webhooks",
+            self.base_url
+        );
+
+        let body = WebhookRequest {
+            name: Some(webhook.name),
+            description: webhook.description,
+            endpoint: Some(WebhookEndpoint {
+                url: Some(webhook.url),
+                ssl_verification: Some(webhook.verify_ssl),
+            }),
+            enabled: Some(webhook.enabled),
+        };
+
+        self.send_json_request::<_, Webhook>(reqwest::Method::POST, &url, body)
+            .await
+    }
+
+    pub async fn get_webhooks(
+        &self,
+        application: &ApplicationIdentifier,
+    ) -> Result<Vec<Webhook>, Error> {
+        let url = format!(
+            "{}/api/http/applications/{application}/webhooks",
+            self.base_url
+        );
+
+        let fields = format!(
+            "data(webhook({WEBHOOK_FIELDS}),subscriptions({SUBSCRIPTION_FIELDS})),next,totalCount"
+        );
+        let query = HashMap::from([("$fields", fields.as_str()), ("$top", "100000")]);
+
+        Ok(self
+            .send_request::<_, WebhooksApiResponse>(&url, query)
+            .await?
+            .data
+            .into_iter()
+            .map(|entry| Webhook {
+                subscriptions: entry.subscriptions,
+                ..entry.webhook
+            })
+            .collect())
+    }
+
+    pub async fn update_webhook(
+        &self,
+        application: &ApplicationIdentifier,
+        webhook_id: &str,
+        update: WebhookUpdate,
+    ) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/applications/{application}/webhooks/{}",
+            self.base_url,
+            encode_path_segment(webhook_id)
+        );
+
+        let endpoint =
+            (update.url.is_some() || update.verify_ssl.is_some()).then_some(WebhookEndpoint {
+                url: update.url,
+                ssl_verification: update.verify_ssl,
+            });
+        let body = WebhookRequest {
+            name: update.name,
+            description: update.description,
+            endpoint,
+            enabled: update.enabled,
+        };
+
+        self.send_request_without_response(reqwest::Method::PATCH, &url, Some(body))
+            .await
+    }
+
+    pub async fn delete_webhook(
+        &self,
+        application: &ApplicationIdentifier,
+        webhook_id: &str,
+    ) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/applications/{application}/webhooks/{}",
+            self.base_url,
+            encode_path_segment(webhook_id)
+        );
+
+        self.send_request_without_response::<()>(reqwest::Method::DELETE, &url, None)
+            .await
+    }
+
+    pub async fn create_subscription(
+        &self,
+        application: &ApplicationIdentifier,
+        webhook_id: &str,
+        subscription: NewSubscription,
+    ) -> Result<Subscription, Error> {
+        let url = format!(
+            "{}/api/http/applications/{application}/webhooks/{}/subscriptions",
+            self.base_url,
+            encode_path_segment(webhook_id)
+        );
+
+        let body = SubscriptionRequest {
+            name: Some(subscription.name),
+            enabled: None,
+            subscription: Some(SubscriptionDefinition::new(
+                subscription.events,
+                subscription.filters,
+            )?),
+        };
+
+        self.send_json_request::<_, Subscription>(reqwest::Method::POST, &url, body)
+            .await
+    }
+
+    pub async fn get_subscriptions(
+        &self,
+        application: &ApplicationIdentifier,
+        webhook_id: &str,
+    ) -> Result<Vec<Subscription>, Error> {
+        let url = format!(
+            "{}/api/http/applications/{application}/webhooks/{}/subscriptions",
+            self.base_url,
+            encode_path_segment(webhook_id)
+        );
+
+        let fields = format!("data({SUBSCRIPTION_FIELDS}),next,totalCount");
+        let query = HashMap::from([("$fields", fields.as_str()), ("$top", "100000")]);
+
+        Ok(self
+            .send_request::<_, SubscriptionsApiResponse>(&url, query)
+            .await?
+            .data)
+    }
+
+    // Filters can only be replaced together with the event types they apply to.
+    pub async fn update_subscription(
+        &self,
+        application: &ApplicationIdentifier,
+        webhook_id: &str,
+        subscription_id: &str,
+        update: SubscriptionUpdate,
+    ) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/applications/{application}/webhooks/{}/subscriptions/{}",
+            self.base_url,
+            encode_path_segment(webhook_id),
+            encode_path_segment(subscription_id)
+        );
+
+        let subscription = match (update.events, update.filters) {
+            (Some(events), Some(filters)) => Some(SubscriptionDefinition::new(events, filters)?),
+            // Space replaces the whole definition, so the current filters are sent along.
+            (Some(events), None) => {
+                let current = self
+                    .get_subscriptions(application, webhook_id)
+                    .await?
+                    .into_iter()
+                    .find(|subscription| subscription.id == subscription_id)
+                    .ok_or_else(|| {
+                        Error::InvalidSubscription(format!(
+                            "subscription {subscription_id} was not found"
+                        ))
+                    })?;
+                Some(SubscriptionDefinition::new(events, current.filters)?)
+            }
+            (None, Some(_)) => {
+                return Err(Error::InvalidSubscription(
+                    "filters can only be updated together with event types".to_string(),
+                ));
+            }
+            (None, None) => None,
+        };
+
+        let body = SubscriptionRequest {
+            name: update.name,
+            enabled: update.enabled,
+            subscription,
+        };
+
+        self.send_request_without_response(reqwest::Method::PATCH, &url, Some(body))
+            .await
+    }
+
+    pub async fn delete_subscription(
+        &self,
+        application: &ApplicationIdentifier,
+        webhook_id: &str,
+        subscription_id: &str,
+    ) -> Result<(), Error> {
+        let url = format!(
+            "{}/api/http/applications/{application}/webhooks/{}/subscriptions/{}",
+            self.base_url,
+            encode_path_segment(webhook_id),
+            encode_path_segment(subscription_id)
+        );
+
+        self.send_request_without_response::<()>(reqwest::Method::DELETE, &url, None)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscription_definition_rejects_mixed_subjects() {
+        let result = SubscriptionDefinition::new(
+            vec![
+                WebhookEventType::IssueCreated,
+                WebhookEventType::MessagePosted,
+            ],
+            vec![],
+        );
+
+        assert!(matches!(result, Err(Error::InvalidSubscription(_))));
+    }
+
+    #[test]
+    fn test_subscription_definition_rejects_unknown_events_and_filters() {
+        let unknown_event = SubscriptionDefinition::new(vec![WebhookEventType::Unknown], vec![]);
+        let unknown_filter = SubscriptionDefinition::new(
+            vec![WebhookEventType::IssueCreated],
+            vec![EventFilter::Unknown],
+        );
+
+        assert!(matches!(unknown_event, Err(Error::InvalidSubscription(_))));
+        assert!(matches!(unknown_filter, Err(Error::InvalidSubscription(_))));
+    }
+
+    #[test]
+    fn test_unknown_subscription_is_readable() {
+        let subscription: Subscription = serde_json::from_value(serde_json::json!({
+            "id": "5Ou2Jd3De4Fg",
+            "name": "Deployments",
+            "enabled": true,
+            "subscription": {
+                "subjectCode": "Deployment",
+                "filters": [{ "className": "DeploymentFilterIn", "target": "id:prod" }],
+                "eventTypeCodes": ["Deployment.Finished"]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(subscription.events, vec![WebhookEventType::Unknown]);
+        assert_eq!(subscription.filters, vec![EventFilter::Unknown]);
+    }
+
+    #[test]
+    fn test_subscription_definition_requires_events() {
+        let result = SubscriptionDefinition::new(vec![], vec![]);
+
+        assert!(matches!(result, Err(Error::InvalidSubscription(_))));
+    }
+}
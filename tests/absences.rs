@@ -0,0 +1,208 @@
+use chrono::NaiveDate;
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{Client, Member, NewAbsence};
+use utils::load_fixture;
+
+mod utils;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[tokio::test]
+async fn test_get_member_absences_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/absences")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded(
+                "member".into(),
+                "id:0198ad98-74d8-785e-941b-77f40b4ed03f".into(),
+            ),
+            Matcher::UrlEncoded("since".into(), "2025-07-01".into()),
+            Matcher::UrlEncoded("till".into(), "2025-08-31".into()),
+        ]))
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("absences_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client
+        .get_member_absences(
+            "0198ad98-74d8-785e-941b-77f40b4ed03f",
+            date(2025, 7, 1),
+            date(2025, 8, 31),
+        )
+        .await;
+
+    mock.assert();
+    assert!(result.is_ok());
+
+    let absences = result.unwrap();
+    assert_eq!(absences.len(), 3);
+    assert_eq!(absences[0].reason.as_ref().unwrap().name, "Vacation");
+    assert_eq!(absences[0].since, date(2025, 7, 1));
+    assert_eq!(absences[0].till, date(2025, 7, 14));
+    assert_eq!(absences[0].description, Some("Mountains".to_string()));
+
+    let approval = absences[0].approval.as_ref().unwrap();
+    assert!(approval.approved);
+    assert_eq!(
+        approval.approved_by.as_ref().unwrap().username,
+        "some.teamlead"
+    );
+
+    assert!(absences[1].available);
+    assert!(absences[1].approval.is_none());
+}
+
+#[tokio::test]
+async fn test_is_member_available() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/absences")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("absences_positive_response.json").to_string())
+        .expect(4)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let member = Member {
+        id: "0198ad98-74d8-785e-941b-77f40b4ed03f".to_string(),
+        username: "best.programmer".to_string(),
+    };
+
+    // Approved vacation.
+    assert!(
+        !client
+            .is_member_available(&member, date(2025, 7, 3))
+            .await
+            .unwrap()
+    );
+    // Conference, but marked as available.
+    assert!(
+        client
+            .is_member_available(&member, date(2025, 7, 20))
+            .await
+            .unwrap()
+    );
+    // Rejected vacation.
+    assert!(
+        client
+            .is_member_available(&member, date(2025, 8, 5))
+            .await
+            .unwrap()
+    );
+    // No absence at all.
+    assert!(
+        client
+            .is_member_available(&member, date(2025, 7, 16))
+            .await
+            .unwrap()
+    );
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_create_absence_success() {
+    let mut server = Server::new_async().await;
+
+    let fixture = load_fixture("absences_positive_response.json");
+    let mock = server
+        .mock("POST", "/api/http/absences")
+        .match_header("Authorization", "Bearer test_token")
+        .match_body(Matcher::Json(json!({
+            "member": "id:0198ad98-74d8-785e-941b-77f40b4ed03f",
+            "reason": "0198c2af-9e8d-7c6b-a5f4-e3d2c1b0a901",
+            "description": "Mountains",
+            "since": "2025-07-01",
+            "till": "2025-07-14",
+            "available": false
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture["data"][0].to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut absence = NewAbsence::new(
+        "0198ad98-74d8-785e-941b-77f40b4ed03f",
+        "0198c2af-9e8d-7c6b-a5f4-e3d2c1b0a901",
+        date(2025, 7, 1),
+        date(2025, 7, 14),
+    );
+    absence.description = Some("Mountains".to_string());
+    let result = client.create_absence(absence).await;
+
+    mock.assert();
+    assert_eq!(result.unwrap().id, "0198c2b0-1d2e-7a3b-8c4d-5e6f7a8b9c01");
+}
+
+#[tokio::test]
+async fn test_approve_and_delete_absence() {
+    let mut server = Server::new_async().await;
+
+    let approve_mock = server
+        .mock(
+            "PATCH",
+            "/api/http/absences/0198c2b0-1d2e-7a3b-8c4d-5e6f7a8b9c01/approve",
+        )
+        .match_body(Matcher::Json(json!({ "approve": true })))
+        .with_status(200)
+        .create_async()
+        .await;
+    let delete_mock = server
+        .mock(
+            "DELETE",
+            "/api/http/absences/0198c2b0-1d2e-7a3b-8c4d-5e6f7a8b9c01",
+        )
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    assert!(
+        client
+            .approve_absence("0198c2b0-1d2e-7a3b-8c4d-5e6f7a8b9c01")
+            .await
+            .is_ok()
+    );
+    assert!(
+        client
+            .delete_absence("0198c2b0-1d2e-7a3b-8c4d-5e6f7a8b9c01")
+            .await
+            .is_ok()
+    );
+
+    approve_mock.assert();
+    delete_mock.assert();
+}
+
+#[tokio::test]
+async fn test_delete_absence_not_found() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("DELETE", "/api/http/absences/missing")
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client.delete_absence("missing").await;
+
+    mock.assert();
+    assert!(result.is_err());
+}
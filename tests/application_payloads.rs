@@ -0,0 +1,120 @@
+use std::sync::Mutex;
+
+use space::{
+    ApplicationCommand, ApplicationHandler, ApplicationResponse, Commands, Error, InitPayload,
+    ListCommandsPayload, MenuActionPayload, MenuActionResult, MessagePayload,
+    handle_application_payload,
+};
+use utils::load_fixture;
+
+mod utils;
+
+#[derive(Default)]
+struct DeployBot {
+    received: Mutex<Vec<String>>,
+}
+
+impl ApplicationHandler for DeployBot {
+    async fn list_commands(&self, _payload: ListCommandsPayload) -> Commands {
+        Commands {
+            commands: vec![ApplicationCommand::new("deploy", "Deploy a service")],
+        }
+    }
+
+    async fn message(&self, payload: MessagePayload) {
+        self.received
+            .lock()
+            .unwrap()
+            .push(payload.arguments().join(","));
+    }
+
+    async fn init(&self, payload: InitPayload) {
+        self.received.lock().unwrap().push(payload.client_secret);
+    }
+}
+
+#[tokio::test]
+async fn test_list_commands() {
+    let bot = DeployBot::default();
+    let body = br#"{"className":"ListCommandsPayload","clientId":"client","userId":"user"}"#;
+
+    let response = handle_application_payload(&bot, body).await.unwrap();
+
+    assert_eq!(
+        response.body().unwrap(),
+        r#"{"commands":[{"name":"deploy","description":"Deploy a service"}]}"#
+    );
+}
+
+#[tokio::test]
+async fn test_message_and_init_payloads() {
+    let bot = DeployBot::default();
+    let message = load_fixture("application_message_payload.json").to_string();
+    let init = load_fixture("application_init_payload.json").to_string();
+
+    let message_response = handle_application_payload(&bot, message.as_bytes())
+        .await
+        .unwrap();
+    let init_response = handle_application_payload(&bot, init.as_bytes())
+        .await
+        .unwrap();
+
+    assert_eq!(message_response, ApplicationResponse::Empty);
+    assert_eq!(init_response, ApplicationResponse::Empty);
+    assert_eq!(*bot.received.lock().unwrap(), vec!["api", "s3cr3t"]);
+}
+
+#[tokio::test]
+async fn test_unhandled_menu_action() {
+    let bot = DeployBot::default();
+    let body = load_fixture("application_menu_action_payload.json").to_string();
+
+    let response = handle_application_payload(&bot, body.as_bytes())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        response,
+        ApplicationResponse::MenuAction(MenuActionResult::Failure {
+            message: "Unsupported menu item create-issue".to_string()
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_publication_check_and_invalid_payload() {
+    let bot = DeployBot::default();
+    let body = br#"{"className":"AppPublicationCheckPayload","clientId":"client"}"#;
+
+    let response = handle_application_payload(&bot, body).await.unwrap();
+
+    assert_eq!(response, ApplicationResponse::Empty);
+    assert!(matches!(
+        handle_application_payload(&bot, br#"{"className":"MessagePayload"}"#).await,
+        Err(Error::ApplicationPayload(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_unknown_payload_is_acknowledged() {
+    let bot = DeployBot::default();
+    let body = br#"{"className":"WebhookRequestPayload","clientId":"client"}"#;
+
+    let response = handle_application_payload(&bot, body).await.unwrap();
+
+    assert_eq!(response, ApplicationResponse::Empty);
+    assert!(bot.received.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_menu_action_context() {
+    let body = load_fixture("application_menu_action_payload.json");
+
+    let payload: MenuActionPayload = serde_json::from_value(body).unwrap();
+
+    assert_eq!(payload.menu_item, "create-issue");
+    assert_eq!(
+        payload.context.message_id.as_deref(),
+        Some("0198bc7b-ef88-725e-a7f2-f6f04f9a5411")
+    );
+}
@@ -0,0 +1,134 @@
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{ApplicationIdentifier, Client, PermissionContext, Right, RightStatus};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_get_current_application() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/applications/me")
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("application_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let application = client.get_current_application().await.unwrap();
+
+    mock.assert();
+    assert_eq!(application.name, "Deploy bot");
+    assert_eq!(
+        application.client_id,
+        "2b4f6d8e-1a3c-4e5f-8a9b-0c1d2e3f4a5b"
+    );
+    assert!(!application.archived);
+}
+
+#[tokio::test]
+async fn test_get_authorized_rights() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "GET",
+            "/api/http/applications/me/authorizations/authorized-rights",
+        )
+        .match_query(Matcher::UrlEncoded(
+            "contextIdentifier".into(),
+            "project:id:proj123".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("authorized_rights_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let rights = client
+        .get_authorized_rights(
+            &ApplicationIdentifier::Me,
+            &PermissionContext::Project("proj123".to_string()),
+        )
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(rights.len(), 3);
+    assert_eq!(rights[0].right, Right::ViewIssues);
+    assert!(rights[0].is_granted());
+    assert_eq!(rights[1].status, RightStatus::Pending);
+    assert_eq!(
+        rights[2].right,
+        Right::Other("Project.Secrets.View".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_request_approve_and_remove_rights() {
+    let mut server = Server::new_async().await;
+
+    let rights_path = "/api/http/applications/clientId:bot/authorizations/authorized-rights";
+    let request_mock = server
+        .mock("PATCH", format!("{rights_path}/request-rights").as_str())
+        .match_body(Matcher::Json(json!({
+            "contextIdentifier": "global",
+            "rightCodes": ["Channel.PostMessages", "Profile.View"]
+        })))
+        .with_status(200)
+        .create_async()
+        .await;
+    let approve_mock = server
+        .mock("POST", format!("{rights_path}/approve-rights").as_str())
+        .match_body(Matcher::Json(json!({
+            "contextIdentifier": "channel:id:chan42",
+            "rightCodes": ["Channel.ViewMessages"]
+        })))
+        .with_status(200)
+        .create_async()
+        .await;
+    let remove_mock = server
+        .mock(
+            "DELETE",
+            "/api/http/applications/clientId:bot/authorizations/authorized-contexts/project:id:proj123",
+        )
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let application = ApplicationIdentifier::ClientId("bot".to_string());
+    client
+        .request_rights(
+            &application,
+            &PermissionContext::Global,
+            &[Right::PostMessages, Right::ViewProfiles],
+        )
+        .await
+        .unwrap();
+    client
+        .approve_rights(
+            &application,
+            &PermissionContext::Channel("chan42".to_string()),
+            &[Right::ViewMessages],
+        )
+        .await
+        .unwrap();
+    client
+        .remove_authorization(
+            &application,
+            &PermissionContext::Project("proj123".to_string()),
+        )
+        .await
+        .unwrap();
+
+    request_mock.assert();
+    approve_mock.assert();
+    remove_mock.assert();
+}
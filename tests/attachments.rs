@@ -0,0 +1,34 @@
+use mockito::Server;
+use space::Client;
+
+#[tokio::test]
+async fn test_upload_attachment_encodes_filename() {
+    let mut server = Server::new_async().await;
+
+    let upload_mock = server
+        .mock("POST", "/api/http/uploads")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#""/upload/u1""#)
+        .create_async()
+        .await;
+    let store_mock = server
+        .mock("PUT", "/upload/u1/build%20log%20%2350%3F.txt")
+        .match_body("build fails")
+        .with_status(200)
+        .with_body("att-1")
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let attachment = client
+        .upload_attachment("build log #50?.txt", b"build fails".to_vec())
+        .await
+        .unwrap();
+
+    upload_mock.assert();
+    store_mock.assert();
+    assert_eq!(attachment.id, "att-1");
+    assert_eq!(attachment.filename, "build log #50?.txt");
+    assert_eq!(attachment.size, 11);
+}
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{Client, ExecutionStatus};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_get_repository_jobs_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/projects/id:proj123/automation/jobs")
+        .match_query(Matcher::UrlEncoded("repoFilter".into(), "space-rs".into()))
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("jobs_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let jobs = client
+        .get_repository_jobs("proj123", "space-rs")
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(jobs.len(), 2);
+    assert_eq!(jobs[1].name, "Deploy");
+    assert_eq!(jobs[1].repository, "space-rs");
+    assert!(!jobs[1].archived);
+}
+
+#[tokio::test]
+async fn test_start_job_and_poll_execution() {
+    let mut server = Server::new_async().await;
+
+    let start_mock = server
+        .mock(
+            "POST",
+            "/api/http/projects/id:proj123/automation/jobs/3Rt9Gy1Yc2Mn/start",
+        )
+        .match_body(Matcher::Json(json!({
+            "branch": { "className": "GitBranchSpec.Head", "ref": "release/1.4.0" },
+            "parameters": [
+                { "name": "environment", "value": "production" },
+                { "name": "version", "value": "1.4.0" }
+            ]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "executionId": "4Su0Hz2Zd3No" }).to_string())
+        .create_async()
+        .await;
+    let execution_mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/automation/graph-executions/4Su0Hz2Zd3No",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("job_execution_running_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let parameters = HashMap::from([
+        ("version".to_string(), "1.4.0".to_string()),
+        ("environment".to_string(), "production".to_string()),
+    ]);
+    let execution_id = client
+        .start_job("proj123", "3Rt9Gy1Yc2Mn", "release/1.4.0", &parameters)
+        .await
+        .unwrap();
+    let execution = client.get_job_execution(&execution_id).await.unwrap();
+
+    start_mock.assert();
+    execution_mock.assert();
+    assert_eq!(execution.number, 128);
+    assert_eq!(execution.status, ExecutionStatus::Running);
+    assert!(!execution.status.is_terminal());
+    assert!(execution.finished_at.is_none());
+}
+
+#[tokio::test]
+async fn test_get_failed_execution_step_logs() {
+    let mut server = Server::new_async().await;
+
+    let execution_mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/automation/graph-executions/4Su0Hz2Zd3No",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("job_execution_failed_response.json").to_string())
+        .create_async()
+        .await;
+    let steps_mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/automation/graph-executions/4Su0Hz2Zd3No/steps",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("execution_steps_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let log_mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/automation/step-executions/6Uw2Jb4Bf5Pq/log",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("step_log_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let execution = client.get_job_execution("4Su0Hz2Zd3No").await.unwrap();
+    let steps = client.get_execution_steps(&execution.id).await.unwrap();
+    let failed_step = steps
+        .iter()
+        .find(|step| step.status == ExecutionStatus::Failed)
+        .unwrap();
+    let log = client.get_step_log(&failed_step.id).await.unwrap();
+
+    execution_mock.assert();
+    steps_mock.assert();
+    log_mock.assert();
+    assert!(execution.status.is_terminal());
+    assert!(!execution.status.is_successful());
+    assert_eq!(
+        execution.finished_at,
+        Some(
+            chrono::DateTime::parse_from_rfc3339("2025-06-04T12:05:00Z")
+                .unwrap()
+                .into()
+        )
+    );
+    assert_eq!(log[1], "error: deployment timed out after 300s");
+}
+
+#[tokio::test]
+async fn test_cancel_job_execution_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "POST",
+            "/api/http/projects/automation/graph-executions/4Su0Hz2Zd3No/stop",
+        )
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client.cancel_job_execution("4Su0Hz2Zd3No").await;
+
+    mock.assert();
+    assert!(result.is_ok());
+}
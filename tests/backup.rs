@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use mockito::{Matcher, Mock, Server, ServerGuard};
+use serde_json::json;
+use space::{BackupSummary, Client, Error, RemappedStatus, RestoreOptions, RestoreSummary};
+use utils::load_fixture;
+
+mod utils;
+
+const PROJECT_PATH: &str = "/api/http/projects/id:0198ad98-74d8-7235-a4c6-f0a5368a1fb6";
+
+async fn source_server() -> (ServerGuard, Vec<Mock>) {
+    let mut server = Server::new_async().await;
+
+    let mut mocks = vec![
+        server
+            .mock("GET", "/api/http/projects")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(load_fixture("projects_positive_response.json").to_string())
+            .create_async()
+            .await,
+        server
+            .mock(
+                "GET",
+                format!("{PROJECT_PATH}/planning/issues/statuses").as_str(),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(load_fixture("issue_statuses_positive_response.json").to_string())
+            .create_async()
+            .await,
+        server
+            .mock("GET", format!("{PROJECT_PATH}/planning/tags").as_str())
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(load_fixture("issue_tags_positive_response.json").to_string())
+            .create_async()
+            .await,
+        server
+            .mock("GET", "/api/http/custom-fields-v2/Issue/fields")
+            .match_query(Matcher::UrlEncoded(
+                "scope".into(),
+                "project:id:0198ad98-74d8-7235-a4c6-f0a5368a1fb6".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(load_fixture("issue_custom_fields_positive_response.json").to_string())
+            .create_async()
+            .await,
+        server
+            .mock("GET", format!("{PROJECT_PATH}/planning/issues").as_str())
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("$top".into(), "500".into()),
+                Matcher::UrlEncoded("$skip".into(), "0".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(load_fixture("backup_issues_response.json").to_string())
+            .create_async()
+            .await,
+    ];
+    mocks.push(
+        server
+            .mock("GET", "/api/http/chats/messages")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(load_fixture("issue_messages_positive_response.json").to_string())
+            // The second request returns nothing new and confirms the end of the channel.
+            .expect(2)
+            .create_async()
+            .await,
+    );
+
+    (server, mocks)
+}
+
+async fn download_mock(server: &mut ServerGuard) -> Mock {
+    server
+        .mock("GET", "/d/att-1")
+        .match_header("Authorization", "Bearer test_token")
+        .with_status(200)
+        .with_body("build fails")
+        .create_async()
+        .await
+}
+
+async fn create_backup(directory: &Path) {
+    let (mut server, _mocks) = source_server().await;
+    let _download = download_mock(&mut server).await;
+
+    Client::new(&server.url(), "test_token", None)
+        .backup(directory)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_backup_is_resumable() {
+    let directory = tempfile::tempdir().unwrap();
+    let (mut server, mocks) = source_server().await;
+    let failing_download = server
+        .mock("GET", "/d/att-1")
+        .with_status(500)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let interrupted = client.backup(directory.path()).await;
+
+    failing_download.assert();
+    assert!(matches!(interrupted, Err(Error::Request(_))));
+    assert!(!directory.path().join("attachments/att-1").exists());
+
+    failing_download.remove_async().await;
+    let download = download_mock(&mut server).await;
+    let summary = client.backup(directory.path()).await.unwrap();
+    let repeated = client.backup(directory.path()).await.unwrap();
+
+    for mock in &mocks {
+        mock.assert();
+    }
+    download.assert();
+    let expected = BackupSummary {
+        projects: 1,
+        issues: 1,
+        messages: 5,
+        attachments: 1,
+    };
+    assert_eq!(summary, expected);
+    assert_eq!(repeated, expected);
+    assert_eq!(
+        fs::read_to_string(directory.path().join("attachments/att-1")).unwrap(),
+        "build fails"
+    );
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&fs::read(directory.path().join("manifest.json")).unwrap()).unwrap();
+    assert_eq!(manifest["format_version"], space::BACKUP_FORMAT_VERSION);
+    assert!(manifest["completed_at"].is_string());
+}
+
+#[tokio::test]
+async fn test_restore_remaps_ids() {
+    let directory = tempfile::tempdir().unwrap();
+    create_backup(directory.path()).await;
+
+    let mut server = Server::new_async().await;
+    let new_project = "/api/http/projects/id:new-proj";
+    let projects_mock = server
+        .mock("GET", "/api/http/projects")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data":[]}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let create_project_mock = server
+        .mock("POST", "/api/http/projects")
+        .match_body(Matcher::Json(json!({
+            "key": { "key": "TEST1" },
+            "name": "Test Project",
+            "private": false
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"id":"new-proj","name":"Test Project","key":{"key":"TEST1"}}"#)
+        .create_async()
+        .await;
+    let tags_mock = server
+        .mock("GET", format!("{new_project}/planning/tags").as_str())
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data":[{"id":"new-backend","name":"Backend","parent":null}]}"#)
+        .create_async()
+        .await;
+    let create_tag_mock = server
+        .mock("POST", format!("{new_project}/planning/tags").as_str())
+        .match_query(Matcher::Any)
+        .match_body(Matcher::Json(json!({
+            "path": ["API"],
+            "parentTagId": "new-backend"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"id":"new-api","name":"API","parent":{"id":"new-backend"}}"#)
+        .create_async()
+        .await;
+    let custom_fields_mock = server
+        .mock("GET", "/api/http/custom-fields-v2/Issue/fields")
+        .match_query(Matcher::UrlEncoded(
+            "scope".into(),
+            "project:id:new-proj".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {
+                    "id": "new-priority",
+                    "name": "Priority",
+                    "type": { "className": "EnumCFType" },
+                    "enumValues": [{ "id": "new-high", "value": "High" }]
+                },
+                {
+                    "id": "new-reviewer",
+                    "name": "Reviewer",
+                    "type": { "className": "ProfileCFType" }
+                }
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let create_custom_field_mock = server
+        .mock("POST", "/api/http/custom-fields-v2/Issue/fields")
+        .match_query(Matcher::Any)
+        .match_body(Matcher::Json(json!({
+            "scope": "project:id:new-proj",
+            "name": "Estimate",
+            "type": { "className": "IntCFType" }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"id":"new-estimate","name":"Estimate","type":{"className":"IntCFType"}}"#)
+        .create_async()
+        .await;
+    let statuses_mock = server
+        .mock(
+            "GET",
+            format!("{new_project}/planning/issues/statuses").as_str(),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"[{"id":"new-open","name":"Open","resolved":false},{"id":"new-closed","name":"Closed","resolved":true}]"#,
+        )
+        .expect(2)
+        .create_async()
+        .await;
+    let create_issue_mock = server
+        .mock("POST", format!("{new_project}/planning/issues").as_str())
+        .match_query(Matcher::Any)
+        .match_body(Matcher::Json(json!({
+            "title": "Migrate the build to the new runners",
+            "status": "new-closed",
+            "assignee": "new-dev",
+            "tags": ["new-api"],
+            "customFields": [
+                {
+                    "fieldId": "new-estimate",
+                    "value": { "className": "IntCFInputValue", "value": 3 }
+                },
+                {
+                    "fieldId": "new-priority",
+                    "value": { "className": "EnumCFInputValue", "enumValueId": "new-high" }
+                },
+                {
+                    "fieldId": "new-reviewer",
+                    "value": { "className": "ProfileCFInputValue", "profile": "id:new-dev" }
+                }
+            ]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issue_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let upload_mock = server
+        .mock("POST", "/api/http/uploads")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#""/upload/u1""#)
+        .create_async()
+        .await;
+    let store_mock = server
+        .mock("PUT", "/upload/u1/build.log")
+        .match_body("build fails")
+        .with_status(200)
+        .with_body("new-att")
+        .create_async()
+        .await;
+    let attach_mock = server
+        .mock(
+            "POST",
+            format!(
+                "{new_project}/planning/issues/id:0198ad97-bb88-7c4b-bbe5-cc0a7878c08f/attachments"
+            )
+            .as_str(),
+        )
+        .match_body(Matcher::Json(json!({
+            "attachments": [{
+                "className": "FileAttachment",
+                "id": "new-att",
+                "filename": "build.log",
+                "sizeBytes": 11
+            }]
+        })))
+        .with_status(200)
+        .create_async()
+        .await;
+    let message_mock = server
+        .mock("POST", "/api/http/chats/messages/send-message")
+        .match_body(Matcher::PartialJson(json!({
+            "channel": "issue:id:0198ad97-bb88-7c4b-bbe5-cc0a7878c08f"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("send_message_positive_response.json").to_string())
+        .expect(5)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let options = RestoreOptions {
+        profile_ids: HashMap::from([(
+            "0198ad98-74d8-785e-941b-77f40b4ed03f".to_string(),
+            "new-dev".to_string(),
+        )]),
+    };
+    let summary = client
+        .restore_backup(directory.path(), options.clone())
+        .await
+        .unwrap();
+    let repeated = client
+        .restore_backup(directory.path(), options)
+        .await
+        .unwrap();
+
+    for mock in [
+        projects_mock,
+        create_project_mock,
+        tags_mock,
+        create_tag_mock,
+        custom_fields_mock,
+        create_custom_field_mock,
+        statuses_mock,
+        create_issue_mock,
+        upload_mock,
+        store_mock,
+        attach_mock,
+        message_mock,
+    ] {
+        mock.assert();
+    }
+    assert_eq!(
+        summary,
+        RestoreSummary {
+            projects: 1,
+            tags: 1,
+            custom_fields: 1,
+            issues: 1,
+            messages: 5,
+            attachments: 1,
+            remapped_statuses: vec![RemappedStatus {
+                project_key: "TEST1".to_string(),
+                archived_status: "Done".to_string(),
+                target_status: "Closed".to_string(),
+            }],
+        }
+    );
+    assert_eq!(repeated, RestoreSummary::default());
+}
+
+#[tokio::test]
+async fn test_restore_rejects_incomplete_backup() {
+    let directory = tempfile::tempdir().unwrap();
+    fs::write(
+        directory.path().join("manifest.json"),
+        r#"{"format_version":1,"source":"https://old.jetbrains.space","started_at":"2025-10-22T08:00:00Z","completed_at":null}"#,
+    )
+    .unwrap();
+
+    let client = Client::new("https://new.jetbrains.space", "test_token", None);
+    let result = client
+        .restore_backup(directory.path(), RestoreOptions::new())
+        .await;
+
+    assert!(matches!(result, Err(Error::UnsupportedArchive(_))));
+}
@@ -0,0 +1,58 @@
+use mockito::{Matcher, Server};
+use space::{BlockingClient, ChannelIdentifier, ChatMessage, IssuesQuery};
+use utils::load_fixture;
+
+mod utils;
+
+#[test]
+fn test_blocking_get_projects() {
+    let mut server = Server::new();
+
+    let mock = server
+        .mock("GET", "/api/http/projects")
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("projects_positive_response.json").to_string())
+        .create();
+
+    let client = BlockingClient::new(&server.url(), "test_token", None).unwrap();
+    let projects = client.get_projects().unwrap();
+
+    mock.assert();
+    assert!(!projects.is_empty());
+}
+
+#[test]
+fn test_blocking_get_issues_and_send_message() {
+    let mut server = Server::new();
+
+    let issues_mock = server
+        .mock("GET", "/api/http/projects/id:proj123/planning/issues")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issues_positive_response.json").to_string())
+        .create();
+    let message_mock = server
+        .mock("POST", "/api/http/chats/messages/send-message")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("send_message_positive_response.json").to_string())
+        .create();
+
+    let client = BlockingClient::new(&server.url(), "test_token", None).unwrap();
+    let issues = client
+        .get_issues_for_project(IssuesQuery::new("proj123"))
+        .unwrap();
+    client
+        .send_message(
+            ChannelIdentifier::Issue(issues[0].id.clone()),
+            ChatMessage::text("Synced"),
+        )
+        .unwrap();
+
+    issues_mock.assert();
+    message_mock.assert();
+}
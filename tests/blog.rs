@@ -0,0 +1,128 @@
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{ArticleUpdate, ArticlesQuery, Client, NewArticle};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_get_articles_for_project() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/blog")
+        .match_query(Matcher::UrlEncoded("project".into(), "proj123".into()))
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("articles_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut query = ArticlesQuery::new();
+    query.project_id = Some("proj123".to_string());
+    let articles = client.get_articles(query).await.unwrap();
+
+    mock.assert();
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].title, "Release 1.4.0");
+    assert_eq!(articles[0].content, "## What's new\n\n- Merge requests API");
+    assert_eq!(
+        articles[0].created_at,
+        chrono::DateTime::parse_from_rfc3339("2025-06-04T12:00:00Z").unwrap()
+    );
+    assert_eq!(
+        articles[0].published_at.unwrap(),
+        chrono::DateTime::parse_from_rfc3339("2025-06-05T08:00:00Z").unwrap()
+    );
+    assert!(articles[1].published_at.is_none());
+    assert_eq!(articles[0].project_ids, vec!["proj123"]);
+    assert!(articles[1].author.is_none());
+}
+
+#[tokio::test]
+async fn test_get_article_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "GET",
+            "/api/http/blog/id:0198d1a1-1a2b-7c3d-8e4f-5a6b7c8d9e11",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("article_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let article = client
+        .get_article("0198d1a1-1a2b-7c3d-8e4f-5a6b7c8d9e11")
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(article.author.unwrap().username, "release.bot");
+    assert_eq!(
+        article.team_ids,
+        vec!["0198b1c2-d3e4-7f56-8a9b-0c1d2e3f4a5b"]
+    );
+}
+
+#[tokio::test]
+async fn test_create_and_update_article() {
+    let mut server = Server::new_async().await;
+
+    let create_mock = server
+        .mock("POST", "/api/http/blog")
+        .match_body(Matcher::Json(json!({
+            "title": "Release 1.4.0",
+            "content": "## What's new",
+            "publicationDate": "2025-06-04T12:00:00Z",
+            "teams": ["id:0198b1c2-d3e4-7f56-8a9b-0c1d2e3f4a5b"],
+            "locations": [],
+            "projects": ["id:proj123"]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("article_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let update_mock = server
+        .mock(
+            "PATCH",
+            "/api/http/blog/id:0198d1a1-1a2b-7c3d-8e4f-5a6b7c8d9e11",
+        )
+        .match_body(Matcher::Json(json!({
+            "content": "## What's new\n\n- Merge requests API",
+            "locations": ["id:berlin"]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("article_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut new_article = NewArticle::new("Release 1.4.0", "## What's new");
+    new_article.publish_at = Some(
+        chrono::DateTime::parse_from_rfc3339("2025-06-04T12:00:00Z")
+            .unwrap()
+            .into(),
+    );
+    new_article.team_ids = vec!["0198b1c2-d3e4-7f56-8a9b-0c1d2e3f4a5b".to_string()];
+    new_article.project_ids = vec!["proj123".to_string()];
+    let article = client.create_article(new_article).await.unwrap();
+    let update = ArticleUpdate {
+        content: Some("## What's new\n\n- Merge requests API".to_string()),
+        location_ids: Some(vec!["berlin".to_string()]),
+        ..ArticleUpdate::default()
+    };
+    client.update_article(&article.id, update).await.unwrap();
+
+    create_mock.assert();
+    update_mock.assert();
+}
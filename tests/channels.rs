@@ -0,0 +1,170 @@
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{ChannelIdentifier, ChannelsQuery, Client, MessagesQuery, NewChannel};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_get_channels_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/chats/channels/all-channels")
+        .match_query(Matcher::Any)
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("channels_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client.get_channels(ChannelsQuery::new()).await;
+
+    mock.assert();
+    assert!(result.is_ok());
+
+    let channels = result.unwrap();
+    assert_eq!(channels.len(), 2);
+    assert_eq!(channels[0].id, "0198c6b3-4a5b-7c6d-8e7f-9a0b1c2d3e01");
+    assert_eq!(channels[0].name, "general");
+    assert!(!channels[0].private);
+    assert!(channels[1].private);
+    assert_eq!(
+        channels[1].identifier(),
+        ChannelIdentifier::Id("0198c6b3-4a5b-7e8f-9a0b-1c2d3e4f5a02".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_create_and_archive_channel() {
+    let mut server = Server::new_async().await;
+
+    let fixture = load_fixture("channels_positive_response.json");
+    let create_mock = server
+        .mock("POST", "/api/http/chats/channels")
+        .match_body(Matcher::Json(json!({
+            "name": "release-bot",
+            "private": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture["data"][1].to_string())
+        .create_async()
+        .await;
+    let archive_mock = server
+        .mock(
+            "POST",
+            "/api/http/chats/channels/id:0198c6b3-4a5b-7e8f-9a0b-1c2d3e4f5a02/archive",
+        )
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut new_channel = NewChannel::new("release-bot");
+    new_channel.private = true;
+    let channel = client.create_channel(new_channel).await.unwrap();
+    let result = client.archive_channel(&channel.identifier()).await;
+
+    create_mock.assert();
+    archive_mock.assert();
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_restore_channel_encodes_identifier() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "POST",
+            "/api/http/chats/channels/channel:name:release%2342%2Fhotfix/restore-archived",
+        )
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client
+        .restore_channel(&ChannelIdentifier::Channel("release#42/hotfix".to_string()))
+        .await;
+
+    mock.assert();
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_add_and_remove_channel_members() {
+    let mut server = Server::new_async().await;
+
+    let body = json!({ "profiles": ["id:0198ad98-74d8-785e-941b-77f40b4ed03f"] });
+    let add_mock = server
+        .mock(
+            "PATCH",
+            "/api/http/chats/channels/channel:name:general/subscribers/users",
+        )
+        .match_body(Matcher::Json(body.clone()))
+        .with_status(200)
+        .create_async()
+        .await;
+    let remove_mock = server
+        .mock(
+            "DELETE",
+            "/api/http/chats/channels/channel:name:general/subscribers/users",
+        )
+        .match_body(Matcher::Json(body))
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let channel = ChannelIdentifier::Channel("general".to_string());
+    let profiles = ["0198ad98-74d8-785e-941b-77f40b4ed03f"];
+
+    assert!(
+        client
+            .add_channel_members(&channel, &profiles)
+            .await
+            .is_ok()
+    );
+    assert!(
+        client
+            .remove_channel_members(&channel, &profiles)
+            .await
+            .is_ok()
+    );
+
+    add_mock.assert();
+    remove_mock.assert();
+}
+
+#[tokio::test]
+async fn test_get_channel_messages_for_direct_message() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(Matcher::UrlEncoded(
+            "channel".into(),
+            "member:id:0198ad98-74d8-785e-941b-77f40b4ed03f".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issue_messages_positive_response.json").to_string())
+        // The second request returns nothing new and confirms the end of the channel.
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let query = MessagesQuery::for_channel(ChannelIdentifier::Profile(
+        "0198ad98-74d8-785e-941b-77f40b4ed03f".to_string(),
+    ));
+    let result = client.get_channel_messages(query).await;
+
+    mock.assert();
+    assert_eq!(result.unwrap().len(), 5);
+}
@@ -15,7 +15,7 @@ async fn test_get_projects_success() {
         .match_header("Accept", "application/json")
         .with_status(200)
         .with_header("content-type", "application/json")
-        .with_body(&load_fixture("projects_positive_response.json").to_string())
+        .with_body(load_fixture("projects_positive_response.json").to_string())
         .create_async()
         .await;
 
@@ -0,0 +1,187 @@
+use mockito::{Matcher, Server};
+use space::{
+    ChangeType, Client, CodeReviewParticipantRole, CodeReviewState, CodeReviewsQuery, ReviewerState,
+};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_get_code_reviews_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/projects/id:proj123/code-reviews")
+        .match_query(Matcher::UrlEncoded("state".into(), "Opened".into()))
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("code_reviews_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut query = CodeReviewsQuery::new("proj123");
+    query.state = Some(CodeReviewState::Opened);
+    let result = client.get_code_reviews(query).await;
+
+    mock.assert();
+    assert!(result.is_ok());
+
+    let reviews = result.unwrap();
+    assert_eq!(reviews.len(), 2);
+    assert_eq!(reviews[0].number, 17);
+    assert_eq!(reviews[0].title, "Add pagination to issues list");
+    assert_eq!(reviews[0].state, CodeReviewState::Opened);
+    assert_eq!(
+        reviews[0].created_at,
+        chrono::DateTime::parse_from_rfc3339("2025-06-03T12:00:00Z").unwrap()
+    );
+    assert_eq!(
+        reviews[0].created_by.as_ref().unwrap().username,
+        "best.programmer"
+    );
+
+    let reviewer = &reviews[0].participants[1];
+    assert_eq!(reviewer.user.username, "some.teamlead");
+    assert_eq!(reviewer.role, CodeReviewParticipantRole::Reviewer);
+    assert_eq!(reviewer.state, Some(ReviewerState::Accepted));
+
+    assert_eq!(reviews[1].state, CodeReviewState::Closed);
+    assert!(reviews[1].created_by.is_none());
+}
+
+#[tokio::test]
+async fn test_get_code_reviews_with_unknown_state_and_role() {
+    let mut server = Server::new_async().await;
+
+    let mut fixture = load_fixture("code_reviews_positive_response.json");
+    fixture["data"][0]["review"]["participants"][1]["role"] = "Observer".into();
+    fixture["data"][1]["review"]["state"] = "Archived".into();
+    let mock = server
+        .mock("GET", "/api/http/projects/id:proj123/code-reviews")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture.to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let reviews = client
+        .get_code_reviews(CodeReviewsQuery::new("proj123"))
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(
+        reviews[0].participants[1].role,
+        CodeReviewParticipantRole::Unknown
+    );
+    assert_eq!(reviews[1].state, CodeReviewState::Unknown);
+}
+
+#[tokio::test]
+async fn test_get_code_review_participants_success() {
+    let mut server = Server::new_async().await;
+
+    let fixture = load_fixture("code_reviews_positive_response.json");
+    let mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/id:proj123/code-reviews/id:0198c8e5-1a2b-7c3d-8e4f-5a6b7c8d9e01",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture["data"][0]["review"].to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let participants = client
+        .get_code_review_participants("proj123", "0198c8e5-1a2b-7c3d-8e4f-5a6b7c8d9e01")
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(participants.len(), 2);
+    assert_eq!(participants[0].role, CodeReviewParticipantRole::Author);
+    assert!(participants[0].state.is_none());
+}
+
+#[tokio::test]
+async fn test_get_code_review_commits_and_changes() {
+    let mut server = Server::new_async().await;
+
+    let review_path =
+        "/api/http/projects/id:proj123/code-reviews/id:0198c8e5-1a2b-7c3d-8e4f-5a6b7c8d9e01";
+    let commits_mock = server
+        .mock("GET", format!("{review_path}/commits").as_str())
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("code_review_commits_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let changes_mock = server
+        .mock("GET", format!("{review_path}/changes").as_str())
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("code_review_changes_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let commits = client
+        .get_code_review_commits("proj123", "0198c8e5-1a2b-7c3d-8e4f-5a6b7c8d9e01")
+        .await
+        .unwrap();
+    let changes = client
+        .get_code_review_changes("proj123", "0198c8e5-1a2b-7c3d-8e4f-5a6b7c8d9e01")
+        .await
+        .unwrap();
+
+    commits_mock.assert();
+    changes_mock.assert();
+
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0].id, "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678");
+    assert_eq!(commits[0].author.email, "best.programmer@example.com");
+    assert_eq!(commits[0].parents.len(), 1);
+
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].change_type, ChangeType::Modified);
+    assert_eq!(changes[0].old_path, Some("/src/lib.rs".to_string()));
+    assert_eq!(changes[1].change_type, ChangeType::Added);
+    assert!(changes[1].old_path.is_none());
+    assert_eq!(changes[1].new_path, Some("/src/pagination.rs".to_string()));
+}
+
+#[tokio::test]
+async fn test_get_code_review_messages_uses_review_channel() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(Matcher::UrlEncoded(
+            "channel".into(),
+            "codeReview:id:0198c8e5-1a2b-7c3d-8e4f-5a6b7c8d9e01".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issue_messages_positive_empty_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let messages = client
+        .get_code_review_messages("0198c8e5-1a2b-7c3d-8e4f-5a6b7c8d9e01")
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert!(messages.is_empty());
+}
@@ -0,0 +1,79 @@
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{Client, CustomFieldType, EnumValue, NewCustomField};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_get_issue_custom_fields_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/custom-fields-v2/Issue/fields")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("scope".into(), "project:id:proj123".into()),
+            Matcher::UrlEncoded(
+                "$fields".into(),
+                "id,name,type,archived,enumValues(id,value)".into(),
+            ),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issue_custom_fields_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let fields = client.get_issue_custom_fields("proj123").await.unwrap();
+
+    mock.assert();
+    assert_eq!(fields.len(), 4);
+    assert_eq!(fields[1].name, "Priority");
+    assert_eq!(fields[1].field_type, CustomFieldType::Enum);
+    assert_eq!(
+        fields[1].enum_values[1],
+        EnumValue {
+            id: "ev-high".to_string(),
+            value: "High".to_string(),
+        }
+    );
+    assert_eq!(fields[3].field_type, CustomFieldType::Unsupported);
+}
+
+#[tokio::test]
+async fn test_create_issue_custom_field_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/http/custom-fields-v2/Issue/fields")
+        .match_query(Matcher::UrlEncoded(
+            "$fields".into(),
+            "id,name,type,archived,enumValues(id,value)".into(),
+        ))
+        .match_body(Matcher::Json(json!({
+            "scope": "project:id:proj123",
+            "name": "Priority",
+            "type": { "className": "EnumCFType" },
+            "enumValues": ["Low", "High"]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"id":"cf-priority","name":"Priority","type":{"className":"EnumCFType"},"enumValues":[{"id":"ev-low","value":"Low"},{"id":"ev-high","value":"High"}]}"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut field = NewCustomField::new("Priority", CustomFieldType::Enum);
+    field.enum_values = vec!["Low".to_string(), "High".to_string()];
+    let created = client
+        .create_issue_custom_field("proj123", field)
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(created.id, "cf-priority");
+    assert_eq!(created.enum_values.len(), 2);
+}
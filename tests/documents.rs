@@ -0,0 +1,175 @@
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{Client, DocumentBody, DocumentSpace, DocumentUpdate, NewDocument};
+use utils::load_fixture;
+
+mod utils;
+
+const RUNBOOKS_FOLDER_ID: &str = "0198d0a1-1111-7a2b-8c3d-4e5f6a7b8c01";
+
+#[tokio::test]
+async fn test_get_document_folders_and_documents() {
+    let mut server = Server::new_async().await;
+
+    let folders_mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/id:proj123/documents/folders/root/subfolders",
+        )
+        .match_query(Matcher::Any)
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("document_folders_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let documents_mock = server
+        .mock(
+            "GET",
+            format!(
+                "/api/http/projects/id:proj123/documents/folders/id:{RUNBOOKS_FOLDER_ID}/documents"
+            )
+            .as_str(),
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("folder_documents_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let space = DocumentSpace::Project("proj123".to_string());
+    let folders = client.get_document_folders(&space, None).await.unwrap();
+    let documents = client
+        .get_folder_documents(&space, Some(&folders[0].id))
+        .await
+        .unwrap();
+
+    folders_mock.assert();
+    documents_mock.assert();
+    assert_eq!(folders.len(), 2);
+    assert_eq!(folders[0].name, "Runbooks");
+    assert!(folders[0].parent_id.is_none());
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents[0].title, "Database failover");
+    assert_eq!(documents[0].folder_id.as_deref(), Some(RUNBOOKS_FOLDER_ID));
+    assert!(documents[0].body.is_none());
+}
+
+#[tokio::test]
+async fn test_get_document_content() {
+    let mut server = Server::new_async().await;
+
+    let text_mock = server
+        .mock(
+            "GET",
+            "/api/http/team-directory/profiles/id:0198ad98/documents/id:0198d0b2-3333-7a2b-8c3d-4e5f6a7b8c03",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("document_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let checklist_mock = server
+        .mock(
+            "GET",
+            "/api/http/team-directory/profiles/id:0198ad98/documents/id:0198d0b2-4444-7a2b-8c3d-4e5f6a7b8c04",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("document_checklist_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let space = DocumentSpace::Personal("0198ad98".to_string());
+    let document = client
+        .get_document(&space, "0198d0b2-3333-7a2b-8c3d-4e5f6a7b8c03")
+        .await
+        .unwrap();
+    let checklist = client
+        .get_document(&space, "0198d0b2-4444-7a2b-8c3d-4e5f6a7b8c04")
+        .await
+        .unwrap();
+
+    text_mock.assert();
+    checklist_mock.assert();
+    assert_eq!(
+        document.text(),
+        Some("# Database failover\n\n1. Promote the replica")
+    );
+    assert_eq!(checklist.body, Some(DocumentBody::Unsupported));
+    assert!(checklist.text().is_none());
+}
+
+#[tokio::test]
+async fn test_create_update_and_move_document() {
+    let mut server = Server::new_async().await;
+
+    let create_mock = server
+        .mock("POST", "/api/http/projects/id:proj123/documents")
+        .match_body(Matcher::Json(json!({
+            "name": "Database failover",
+            "folder": format!("id:{RUNBOOKS_FOLDER_ID}"),
+            "bodyIn": {
+                "className": "TextDocumentBodyCreateIn",
+                "text": "# Database failover"
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("document_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let update_mock = server
+        .mock(
+            "PATCH",
+            "/api/http/projects/id:proj123/documents/id:0198d0b2-3333-7a2b-8c3d-4e5f6a7b8c03",
+        )
+        .match_body(Matcher::Json(json!({
+            "updateIn": {
+                "className": "TextDocumentBodyUpdateIn",
+                "text": "# Database failover\n\n1. Promote the replica"
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("document_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let move_mock = server
+        .mock(
+            "POST",
+            "/api/http/projects/id:proj123/documents/id:0198d0b2-3333-7a2b-8c3d-4e5f6a7b8c03/move",
+        )
+        .match_body(Matcher::Json(json!({ "folder": "root" })))
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let space = DocumentSpace::Project("proj123".to_string());
+    let mut new_document = NewDocument::new("Database failover", "# Database failover");
+    new_document.folder_id = Some(RUNBOOKS_FOLDER_ID.to_string());
+    let document = client.create_document(&space, new_document).await.unwrap();
+    let update = DocumentUpdate {
+        text: Some("# Database failover\n\n1. Promote the replica".to_string()),
+        ..DocumentUpdate::default()
+    };
+    client
+        .update_document(&space, &document.id, update)
+        .await
+        .unwrap();
+    client
+        .move_document(&space, &document.id, None)
+        .await
+        .unwrap();
+
+    create_mock.assert();
+    update_mock.assert();
+    move_mock.assert();
+}
@@ -0,0 +1,172 @@
+use chrono_tz::Europe::Berlin;
+use mockito::{Matcher, Server};
+use space::{
+    Client, CsvExporter, Error, ExportOptions, Issue, IssueColumn, IssuesQuery, JsonLinesExporter,
+    MarkdownTranscript, MessageColumn, MessagesQuery, RecordWriter,
+};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_export_issues_to_csv_in_pages() {
+    let mut server = Server::new_async().await;
+
+    let first_page = server
+        .mock("GET", "/api/http/projects/id:proj123/planning/issues")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("$top".into(), "2".into()),
+            Matcher::UrlEncoded("$skip".into(), "0".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issues_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("GET", "/api/http/projects/id:proj123/planning/issues")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("$top".into(), "2".into()),
+            Matcher::UrlEncoded("$skip".into(), "2".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data":[]}"#)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut query = IssuesQuery::new("proj123");
+    query.top = 2;
+    let mut exporter = CsvExporter::new(
+        Vec::new(),
+        &[
+            IssueColumn::Number,
+            IssueColumn::Title,
+            IssueColumn::Assignee,
+            IssueColumn::CreatedAt,
+        ],
+        ExportOptions::new()
+            .time_zone(Berlin)
+            .date_format("%Y-%m-%d %H:%M"),
+    )
+    .unwrap();
+    let exported = client.export_issues(query, &mut exporter).await.unwrap();
+    let csv = String::from_utf8(exporter.finish().unwrap()).unwrap();
+
+    first_page.assert();
+    second_page.assert();
+    assert_eq!(exported, 2);
+    assert_eq!(
+        csv,
+        "number,title,assignee,created_at\n\
+         1,Test title for first issue,,2025-10-22 10:36\n\
+         2,Test title for second issue,Best Programmer,2025-10-22 10:36\n"
+    );
+}
+
+#[tokio::test]
+async fn test_export_issue_conversation() {
+    let mut server = Server::new_async().await;
+
+    let issue_mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/id:proj123/planning/issues/number:1",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issue_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let messages_mock = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(Matcher::UrlEncoded(
+            "sorting".into(),
+            "FromOldestToNewest".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issue_messages_positive_response.json").to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let issue = client
+        .get_issue_for_project_by_number("proj123", 1)
+        .await
+        .unwrap();
+    let mut transcript = MarkdownTranscript::new(Vec::new(), &issue, ExportOptions::new()).unwrap();
+    let exported = client
+        .export_issue_messages(&issue, &mut transcript)
+        .await
+        .unwrap();
+    let markdown = String::from_utf8(transcript.finish().unwrap()).unwrap();
+
+    issue_mock.assert();
+    messages_mock.assert();
+    assert!(exported > 0);
+    assert!(markdown.starts_with(
+        "# #1 Test title for first issue\n\n- Status: Open\n- Assignee: Unassigned\n"
+    ));
+    assert!(markdown.contains("\n## Conversation\n"));
+    assert_eq!(markdown.matches("\n**").count(), exported);
+}
+
+#[tokio::test]
+async fn test_export_messages_to_json_lines() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issue_messages_positive_response.json").to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let messages = client
+        .get_issue_messages(MessagesQuery::new("issue123"))
+        .await
+        .unwrap();
+    let mut exporter = JsonLinesExporter::new(
+        Vec::new(),
+        &[MessageColumn::Id, MessageColumn::Author],
+        ExportOptions::new(),
+    );
+    for message in &messages {
+        exporter.write_record(message).unwrap();
+    }
+    let output = String::from_utf8(exporter.finish().unwrap()).unwrap();
+
+    mock.assert();
+    let lines: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), messages.len());
+    assert_eq!(lines[0]["id"], messages[0].id.as_str());
+    assert_eq!(lines[0]["author"], messages[0].author.username.as_str());
+    assert!(lines[0].get("text").is_none());
+}
+
+#[test]
+fn test_export_rejects_invalid_date_format() {
+    let issue: Issue =
+        serde_json::from_value(load_fixture("issue_positive_response.json")).unwrap();
+    let mut exporter = CsvExporter::new(
+        Vec::new(),
+        &[IssueColumn::Number, IssueColumn::CreatedAt],
+        ExportOptions::new().date_format("%Q"),
+    )
+    .unwrap();
+
+    let result = exporter.write_record(&issue);
+
+    assert!(matches!(result, Err(Error::Export(_))));
+}
@@ -0,0 +1,218 @@
+use mockito::{Matcher, Server};
+use space::{Client, CommitsQuery, Project};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_get_project_repositories_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/projects/id:proj123")
+        .match_query(Matcher::Any)
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("project_repositories_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let repositories = client.get_project_repositories("proj123").await.unwrap();
+
+    mock.assert();
+    assert_eq!(repositories.len(), 2);
+    assert_eq!(repositories[0].name, "space-rs");
+    assert!(repositories[1].description.is_none());
+}
+
+#[tokio::test]
+async fn test_get_branches_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/id:proj123/repositories/space-rs/heads",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("branches_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let branches = client.get_branches("proj123", "space-rs").await.unwrap();
+
+    mock.assert();
+    assert_eq!(branches.len(), 2);
+    assert_eq!(branches[0].name, "main");
+    assert_eq!(branches[1].name, "feature/pagination");
+    assert_eq!(
+        branches[1].commit_id,
+        "0f1e2d3c4b5a69788796a5b4c3d2e1f00f1e2d3c"
+    );
+}
+
+#[tokio::test]
+async fn test_get_commits_with_filters() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/id:proj123/repositories/space-rs/commits",
+        )
+        .match_query(Matcher::UrlEncoded(
+            "query".into(),
+            "author:best.programmer date:2025-06-01..2025-06-30".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("commits_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut query = CommitsQuery::new("proj123", "space-rs");
+    query.author = Some("best.programmer".to_string());
+    query.since = chrono::NaiveDate::from_ymd_opt(2025, 6, 1);
+    query.until = chrono::NaiveDate::from_ymd_opt(2025, 6, 30);
+    let commits = client.get_commits(query).await.unwrap();
+
+    mock.assert();
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0].author.name, "Best Programmer");
+    assert_eq!(
+        commits[0].author_date,
+        chrono::DateTime::parse_from_rfc3339("2025-06-03T12:00:00Z").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_get_file_diff_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/id:proj123/repositories/space-rs/text-diff",
+        )
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("baseCommit".into(), "0f1e2d3c".into()),
+            Matcher::UrlEncoded("targetCommit".into(), "a1b2c3d4".into()),
+            Matcher::UrlEncoded("file".into(), "/src/lib.rs".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("file_diff_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let diff = client
+        .get_file_diff("proj123", "space-rs", "0f1e2d3c", "a1b2c3d4", "/src/lib.rs")
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(diff.lines.len(), 3);
+    assert!(!diff.lines[0].is_added() && !diff.lines[0].is_deleted());
+    assert!(diff.lines[1].is_deleted());
+    assert!(diff.lines[2].is_added());
+}
+
+#[tokio::test]
+async fn test_get_commit_changes_encodes_revision() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/id:proj123/repositories/space-rs/commits/feature%2Fpagination/changes",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("code_review_changes_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let changes = client
+        .get_commit_changes("proj123", "space-rs", "feature/pagination")
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert!(!changes.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_commit_issues_success() {
+    let mut server = Server::new_async().await;
+
+    // TEST1-T-99 does not exist and is skipped.
+    let mut commit_body = load_fixture("commits_positive_response.json")["data"][0].clone();
+    commit_body["message"] =
+        "Add pagination to issues list\n\nCloses TEST1-T-12, see TEST1-T-99".into();
+
+    let commit_mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/id:proj123/repositories/space-rs/commits/a1b2c3d4e5f60718293a4b5c6d7e8f9012345678",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(commit_body.to_string())
+        .create_async()
+        .await;
+    let issue_mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/id:proj123/planning/issues/number:12",
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issue_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let missing_issue_mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/id:proj123/planning/issues/number:99",
+        )
+        .match_query(Matcher::Any)
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error":"not-found","error_description":"Issue not found"}"#)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let project = Project {
+        id: "proj123".to_string(),
+        name: "Test Project".to_string(),
+        key: "TEST1".to_string(),
+    };
+    let commit = client
+        .get_commit(
+            "proj123",
+            "space-rs",
+            "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678",
+        )
+        .await
+        .unwrap();
+    let issues = client.get_commit_issues(&project, &commit).await.unwrap();
+
+    commit_mock.assert();
+    issue_mock.assert();
+    missing_issue_mock.assert();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].title, "Test title for first issue");
+}
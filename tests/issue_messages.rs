@@ -1,5 +1,6 @@
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 use mockito::{Matcher, Server};
+use serde_json::{Value, json};
 use space::{Client, MessagesQuery};
 
 mod utils;
@@ -15,8 +16,9 @@ async fn test_get_issue_messages_success() {
         .match_header("Accept", "application/json")
         .with_status(200)
         .with_header("content-type", "application/json")
-        .with_body(&utils::load_fixture("issue_messages_positive_response.json").to_string())
-        .expect(1)
+        .with_body(utils::load_fixture("issue_messages_positive_response.json").to_string())
+        // The second request returns nothing new and confirms the end of the channel.
+        .expect(2)
         .create_async()
         .await;
 
@@ -101,7 +103,7 @@ async fn test_get_issue_messages_empty_success() {
         .match_header("Accept", "application/json")
         .with_status(200)
         .with_header("content-type", "application/json")
-        .with_body(&utils::load_fixture("issue_messages_positive_empty_response.json").to_string())
+        .with_body(utils::load_fixture("issue_messages_positive_empty_response.json").to_string())
         .expect(1)
         .create_async()
         .await;
@@ -115,3 +117,373 @@ async fn test_get_issue_messages_empty_success() {
     let messages = result.unwrap();
     assert_eq!(messages.len(), 0);
 }
+
+fn message_json(id: &str, text: &str, iso: &str) -> Value {
+    json!({
+        "id": id,
+        "text": text,
+        "author": {
+            "name": "Best Programmer",
+            "details": {
+                "className": "CUserPrincipalDetails",
+                "user": { "id": "0198bc7b-ef88-791d-be78-ca843e68e737" }
+            }
+        },
+        "created": { "iso": iso }
+    })
+}
+
+fn page_json(messages: Vec<Value>, next_start_from_date: Option<&str>, org_limit: bool) -> String {
+    json!({
+        "messages": messages,
+        "nextStartFromDate": next_start_from_date.map(|iso| json!({ "iso": iso })),
+        "orgLimitReached": org_limit
+    })
+    .to_string()
+}
+
+fn messages_page(ids: &[&str], next_start_from_date: Option<&str>) -> String {
+    let fixture = utils::load_fixture("issue_messages_positive_response.json");
+    let messages = ids
+        .iter()
+        .map(|id| {
+            fixture["messages"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|message| message["id"] == *id)
+                .unwrap()
+                .clone()
+        })
+        .collect();
+
+    page_json(messages, next_start_from_date, false)
+}
+
+fn page_query(start_from_date: &str, batch_size: usize) -> Matcher {
+    Matcher::AllOf(vec![
+        Matcher::UrlEncoded("startFromDate".into(), start_from_date.into()),
+        Matcher::UrlEncoded("batchSize".into(), batch_size.to_string()),
+    ])
+}
+
+#[tokio::test]
+async fn test_get_issue_messages_newer_than() {
+    let mut server = Server::new_async().await;
+
+    let first_page = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("sorting".into(), "FromOldestToNewest".into()),
+            Matcher::UrlEncoded("startFromDate".into(), "2025-05-29T08:59:36.629Z".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(messages_page(
+            &[
+                "0198bc7b-ef88-7de0-b660-3f0af39c616f",
+                "0198bc7b-ef88-7a78-8057-cf942d304de7",
+                "0198bc7b-ef88-725e-a7f2-f6f04f9a5411",
+            ],
+            Some("2025-05-29T10:24:26.267Z"),
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let last_page = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(Matcher::UrlEncoded(
+            "startFromDate".into(),
+            "2025-05-29T10:24:26.266Z".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(messages_page(
+            &["0198bc7b-ef88-725e-a7f2-f6f04f9a5411"],
+            None,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let since = "2025-05-29T08:59:36.629Z".parse::<DateTime<Utc>>().unwrap();
+    let query = MessagesQuery::new("0198bc7b-ef88-7b76-b9cf-af06e43567ad").newer_than(since);
+    let messages = client.get_issue_messages(query).await.unwrap();
+
+    first_page.assert();
+    last_page.assert();
+    let ids: Vec<&str> = messages.iter().map(|message| message.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec![
+            "0198bc7b-ef88-7a78-8057-cf942d304de7",
+            "0198bc7b-ef88-725e-a7f2-f6f04f9a5411"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_issue_messages_within_stops_after_window() {
+    let mut server = Server::new_async().await;
+
+    let first_page = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("sorting".into(), "FromOldestToNewest".into()),
+            Matcher::UrlEncoded("startFromDate".into(), "2025-05-28T15:00:00Z".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(messages_page(
+            &[
+                "0198bc7b-ef88-792c-8373-7e8ffd64e342",
+                "0198bc7b-ef88-76e0-bf0e-09161e059cd7",
+            ],
+            Some("2025-05-28T15:09:34.649Z"),
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    // Restarting one millisecond before the last message returns the same page, so the
+    // batch is doubled.
+    let repeated_page = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(page_query("2025-05-28T15:09:34.648Z", 2))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(messages_page(
+            &[
+                "0198bc7b-ef88-792c-8373-7e8ffd64e342",
+                "0198bc7b-ef88-76e0-bf0e-09161e059cd7",
+            ],
+            Some("2025-05-28T15:09:34.649Z"),
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let doubled_page = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(page_query("2025-05-28T15:09:34.648Z", 4))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(messages_page(
+            &[
+                "0198bc7b-ef88-792c-8373-7e8ffd64e342",
+                "0198bc7b-ef88-76e0-bf0e-09161e059cd7",
+                "0198bc7b-ef88-7de0-b660-3f0af39c616f",
+                "0198bc7b-ef88-7a78-8057-cf942d304de7",
+            ],
+            Some("2025-05-29T10:24:16.023Z"),
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let since = "2025-05-28T15:00:00Z".parse::<DateTime<Utc>>().unwrap();
+    let until = "2025-05-29T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+    let mut query = MessagesQuery::new("0198bc7b-ef88-7b76-b9cf-af06e43567ad").within(since, until);
+    query.batch_size = 2;
+    let messages = client.get_issue_messages(query).await.unwrap();
+
+    first_page.assert();
+    repeated_page.assert();
+    doubled_page.assert();
+    let ids: Vec<&str> = messages.iter().map(|message| message.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec![
+            "0198bc7b-ef88-792c-8373-7e8ffd64e342",
+            "0198bc7b-ef88-76e0-bf0e-09161e059cd7",
+            "0198bc7b-ef88-7de0-b660-3f0af39c616f"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_issue_messages_older_than() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("sorting".into(), "FromNewestToOldest".into()),
+            Matcher::UrlEncoded("startFromDate".into(), "2025-05-29T08:59:36.629Z".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(messages_page(
+            &[
+                "0198bc7b-ef88-7de0-b660-3f0af39c616f",
+                "0198bc7b-ef88-76e0-bf0e-09161e059cd7",
+                "0198bc7b-ef88-792c-8373-7e8ffd64e342",
+            ],
+            None,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let until = "2025-05-29T08:59:36.629Z".parse::<DateTime<Utc>>().unwrap();
+    let query = MessagesQuery::new("0198bc7b-ef88-7b76-b9cf-af06e43567ad").older_than(until);
+    let messages = client.get_issue_messages(query).await.unwrap();
+
+    mock.assert();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].id, "0198bc7b-ef88-792c-8373-7e8ffd64e342");
+    assert_eq!(messages[1].id, "0198bc7b-ef88-76e0-bf0e-09161e059cd7");
+}
+
+#[tokio::test]
+async fn test_get_issue_messages_identical_timestamps_across_pages() {
+    let mut server = Server::new_async().await;
+
+    let same_time = "2025-06-01T12:00:00Z";
+    let first = message_json("first", "first", same_time);
+    let second = message_json("second", "second", same_time);
+    let third = message_json("third", "third", same_time);
+    let older = message_json("older", "older", "2025-06-01T11:00:00Z");
+
+    let first_page = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(page_query("2025-06-02T00:00:00Z", 2))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_json(
+            vec![first.clone(), second.clone()],
+            Some(same_time),
+            false,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let repeated_page = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(page_query("2025-06-01T12:00:00.001Z", 2))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_json(
+            vec![first.clone(), second.clone()],
+            Some(same_time),
+            false,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let doubled_page = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(page_query("2025-06-01T12:00:00.001Z", 4))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_json(vec![first, second, third, older], None, false))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut query = MessagesQuery::new("0198bc7b-ef88-7b76-b9cf-af06e43567ad");
+    query.batch_size = 2;
+    query.start_from_date = Some("2025-06-02T00:00:00Z".parse().unwrap());
+    let batch = client.get_channel_messages_batch(query).await.unwrap();
+
+    first_page.assert();
+    repeated_page.assert();
+    doubled_page.assert();
+    assert!(!batch.org_limit_reached);
+    let mut ids: Vec<&str> = batch
+        .messages
+        .iter()
+        .map(|message| message.id.as_str())
+        .collect();
+    assert_eq!(ids.remove(0), "older");
+    ids.sort_unstable();
+    assert_eq!(ids, vec!["first", "second", "third"]);
+}
+
+#[tokio::test]
+async fn test_get_issue_messages_continues_after_short_page() {
+    let mut server = Server::new_async().await;
+
+    let first_page = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(page_query("2025-06-02T00:00:00Z", 3))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_json(
+            vec![
+                message_json("newest", "newest", "2025-06-01T12:00:00Z"),
+                message_json("middle", "middle", "2025-06-01T11:00:00Z"),
+            ],
+            Some("2025-06-01T11:00:00Z"),
+            false,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(page_query("2025-06-01T11:00:00.001Z", 3))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_json(
+            vec![
+                message_json("middle", "middle", "2025-06-01T11:00:00Z"),
+                message_json("oldest", "oldest", "2025-06-01T10:00:00Z"),
+            ],
+            Some("2025-06-01T10:00:00Z"),
+            false,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let last_page = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(page_query("2025-06-01T10:00:00.001Z", 3))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_json(vec![], None, false))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut query = MessagesQuery::new("0198bc7b-ef88-7b76-b9cf-af06e43567ad");
+    query.batch_size = 3;
+    query.start_from_date = Some("2025-06-02T00:00:00Z".parse().unwrap());
+    let messages = client.get_issue_messages(query).await.unwrap();
+
+    first_page.assert();
+    second_page.assert();
+    last_page.assert();
+    let ids: Vec<&str> = messages.iter().map(|message| message.id.as_str()).collect();
+    assert_eq!(ids, vec!["oldest", "middle", "newest"]);
+}
+
+#[tokio::test]
+async fn test_get_issue_messages_reports_org_limit() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/chats/messages")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_json(
+            vec![message_json("only", "only", "2025-06-01T12:00:00Z")],
+            Some("2025-06-01T12:00:00Z"),
+            true,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let query = MessagesQuery::new("0198bc7b-ef88-7b76-b9cf-af06e43567ad");
+    let batch = client.get_channel_messages_batch(query).await.unwrap();
+
+    mock.assert();
+    assert!(batch.org_limit_reached);
+    assert_eq!(batch.messages.len(), 1);
+}
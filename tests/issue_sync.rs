@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use mockito::{Matcher, Server};
+use space::{Client, IssueSyncCursor};
+use utils::load_fixture;
+
+mod utils;
+
+const FIRST: &str = "0198ad97-bb88-7c4b-bbe5-cc0a7878c08f";
+const SECOND: &str = "0198ad98-74d8-75db-ae13-ba8265d5caa1";
+const THIRD: &str = "0198ada1-0c4b-7e12-8f55-2d6a9b3c7e02";
+const FOURTH: &str = "0198adb0-1f2e-7d41-9c3a-5be0d1a7f001";
+const REMOVED: &str = "0198ada9-5d10-7a3e-b0c1-77e4f2a6d903";
+
+fn time(iso: &str) -> DateTime<Utc> {
+    iso.parse().unwrap()
+}
+
+#[tokio::test]
+async fn test_initial_sync_reports_everything_as_created() {
+    let mut server = Server::new_async().await;
+
+    let ids_mock = server
+        .mock("GET", "/api/http/projects/id:proj123/planning/issues")
+        .match_query(Matcher::UrlEncoded(
+            "$fields".into(),
+            "data(id),next,totalCount".into(),
+        ))
+        .expect(0)
+        .create_async()
+        .await;
+    let updates_mock = server
+        .mock("GET", "/api/http/projects/id:proj123/planning/issues")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("sorting".into(), "UPDATED".into()),
+            Matcher::UrlEncoded("descending".into(), "true".into()),
+            Matcher::UrlEncoded("$top".into(), "500".into()),
+            Matcher::UrlEncoded("$skip".into(), "0".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issues_sync_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let changes = client
+        .sync_issues("proj123", &IssueSyncCursor::new())
+        .await
+        .unwrap();
+
+    ids_mock.assert();
+    updates_mock.assert();
+    let created: Vec<_> = changes.created.iter().map(|issue| issue.number).collect();
+    assert_eq!(created, [3, 2, 4, 1]);
+    assert!(changes.updated.is_empty());
+    assert!(changes.deleted.is_empty());
+    assert_eq!(
+        changes.cursor.updated_at,
+        Some(time("2025-10-23T10:00:00Z"))
+    );
+    assert_eq!(changes.cursor.issues.len(), 4);
+}
+
+#[tokio::test]
+async fn test_incremental_sync_detects_changes() {
+    let mut server = Server::new_async().await;
+
+    let ids_mock = server
+        .mock("GET", "/api/http/projects/id:proj123/planning/issues")
+        .match_query(Matcher::UrlEncoded(
+            "$fields".into(),
+            "data(id),next,totalCount".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"data":[{{"id":"{FIRST}"}},{{"id":"{SECOND}"}},{{"id":"{THIRD}"}},{{"id":"{FOURTH}"}}]}}"#
+        ))
+        // The missing issue is confirmed by a second listing before it is reported.
+        .expect(2)
+        .create_async()
+        .await;
+    let updates_mock = server
+        .mock("GET", "/api/http/projects/id:proj123/planning/issues")
+        .match_query(Matcher::UrlEncoded("sorting".into(), "UPDATED".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issues_sync_response.json").to_string())
+        .create_async()
+        .await;
+
+    // The second issue was stored two minutes before the previous sync finished, it is read
+    // again because of the clock skew allowance but is not reported twice.
+    let cursor = IssueSyncCursor {
+        updated_at: Some(time("2025-10-23T09:00:00Z")),
+        issues: BTreeMap::from([
+            (FIRST.to_string(), time("2025-10-23T09:00:00Z")),
+            (SECOND.to_string(), time("2025-10-23T08:58:00Z")),
+            (THIRD.to_string(), time("2025-10-23T08:00:00Z")),
+            (REMOVED.to_string(), time("2025-10-22T12:00:00Z")),
+        ]),
+    };
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let changes = client.sync_issues("proj123", &cursor).await.unwrap();
+
+    ids_mock.assert();
+    updates_mock.assert();
+    let created: Vec<_> = changes
+        .created
+        .iter()
+        .map(|issue| issue.id.as_str())
+        .collect();
+    let updated: Vec<_> = changes
+        .updated
+        .iter()
+        .map(|issue| issue.id.as_str())
+        .collect();
+    assert_eq!(created, [FOURTH]);
+    assert_eq!(updated, [FIRST]);
+    assert_eq!(changes.deleted, [REMOVED]);
+    assert_eq!(
+        changes.cursor,
+        IssueSyncCursor {
+            updated_at: Some(time("2025-10-23T10:00:00Z")),
+            issues: BTreeMap::from([
+                (FIRST.to_string(), time("2025-10-23T10:00:00Z")),
+                (SECOND.to_string(), time("2025-10-23T08:58:00Z")),
+                (THIRD.to_string(), time("2025-10-23T08:00:00Z")),
+                (FOURTH.to_string(), time("2025-10-23T09:30:00Z")),
+            ]),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_sync_without_changes_keeps_cursor() {
+    let mut server = Server::new_async().await;
+
+    let ids_mock = server
+        .mock("GET", "/api/http/projects/id:proj123/planning/issues")
+        .match_query(Matcher::UrlEncoded(
+            "$fields".into(),
+            "data(id),next,totalCount".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"data":[{{"id":"{FIRST}"}},{{"id":"{SECOND}"}},{{"id":"{THIRD}"}},{{"id":"{FOURTH}"}}]}}"#
+        ))
+        .create_async()
+        .await;
+    let updates_mock = server
+        .mock("GET", "/api/http/projects/id:proj123/planning/issues")
+        .match_query(Matcher::UrlEncoded("sorting".into(), "UPDATED".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issues_sync_response.json").to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let first = client
+        .sync_issues("proj123", &IssueSyncCursor::new())
+        .await
+        .unwrap();
+    let second = client.sync_issues("proj123", &first.cursor).await.unwrap();
+
+    ids_mock.assert();
+    updates_mock.assert();
+    assert!(second.is_empty());
+    assert_eq!(second.cursor, first.cursor);
+}
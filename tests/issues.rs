@@ -1,5 +1,6 @@
 use mockito::{Matcher, Server};
-use space::Client;
+use serde_json::json;
+use space::{Client, IssueUpdate, NewIssue};
 use utils::load_fixture;
 
 mod utils;
@@ -124,7 +125,7 @@ async fn test_get_issues_for_project_success() {
         .match_header("Accept", "application/json")
         .with_status(200)
         .with_header("content-type", "application/json")
-        .with_body(&load_fixture("issues_positive_response.json").to_string())
+        .with_body(load_fixture("issues_positive_response.json").to_string())
         .create_async()
         .await;
 
@@ -190,3 +191,83 @@ async fn test_get_issues_for_project_success() {
     assert_eq!(issue2_assignee.id, "0198ad98-74d8-785e-941b-77f40b4ed03f");
     assert_eq!(issue2_assignee.username, "Best Programmer");
 }
+
+#[tokio::test]
+async fn test_get_issue_statuses() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "GET",
+            "/api/http/projects/id:proj123/planning/issues/statuses",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issue_statuses_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let statuses = client.get_issue_statuses("proj123").await.unwrap();
+
+    mock.assert();
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses[1].name, "Done");
+    assert!(statuses[1].resolved);
+}
+
+#[tokio::test]
+async fn test_create_and_update_issue() {
+    let mut server = Server::new_async().await;
+
+    let create_mock = server
+        .mock("POST", "/api/http/projects/id:proj123/planning/issues")
+        .match_query(Matcher::Any)
+        .match_body(Matcher::Json(json!({
+            "title": "Test title for first issue",
+            "description": "Test description for first issue",
+            "status": "0198ad98-74d8-715a-8a23-fb4c9e9bd30c"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("issue_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let update_mock = server
+        .mock(
+            "PATCH",
+            "/api/http/projects/id:proj123/planning/issues/id:0198ad97-bb88-7c4b-bbe5-cc0a7878c08f",
+        )
+        .match_body(Matcher::Json(json!({
+            "status": "0198ad98-74d8-715a-8a23-fb4c9e9bd30d",
+            "assignee": "0198ad98-1274-7980-a83a-8e6036fd17bb"
+        })))
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut issue = NewIssue::new(
+        "proj123",
+        "Test title for first issue",
+        "0198ad98-74d8-715a-8a23-fb4c9e9bd30c",
+    );
+    issue.description = Some("Test description for first issue".to_string());
+    let issue = client.create_issue(issue).await.unwrap();
+    client
+        .update_issue(
+            "proj123",
+            &issue.id,
+            IssueUpdate {
+                status_id: Some("0198ad98-74d8-715a-8a23-fb4c9e9bd30d".to_string()),
+                assignee_id: Some("0198ad98-1274-7980-a83a-8e6036fd17bb".to_string()),
+                ..IssueUpdate::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    create_mock.assert();
+    update_mock.assert();
+    assert_eq!(issue.number, 1);
+}
@@ -0,0 +1,154 @@
+use chrono::{DateTime, Utc};
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{Client, MeetingSchedule, MeetingUpdate, NewMeeting, Recurrence, RecurrenceFrequency};
+use utils::load_fixture;
+
+mod utils;
+
+fn utc(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value).unwrap().into()
+}
+
+#[tokio::test]
+async fn test_get_profile_meetings_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/calendars/meetings")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded(
+                "profiles".into(),
+                "id:0198ad98-74d8-785e-941b-77f40b4ed03f".into(),
+            ),
+            Matcher::UrlEncoded("startingAfter".into(), "2025-06-01T00:00:00Z".into()),
+            Matcher::UrlEncoded("endingBefore".into(), "2025-06-30T00:00:00Z".into()),
+        ]))
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("meetings_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let meetings = client
+        .get_profile_meetings(
+            "0198ad98-74d8-785e-941b-77f40b4ed03f",
+            utc("2025-06-01T00:00:00Z"),
+            utc("2025-06-30T00:00:00Z"),
+        )
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(meetings.len(), 2);
+    assert_eq!(meetings[0].summary, "Sprint planning");
+    assert_eq!(meetings[0].schedule.start, utc("2025-06-02T08:00:00Z"));
+    assert_eq!(
+        meetings[0].schedule.time_zone.as_deref(),
+        Some("Europe/Berlin")
+    );
+    let recurrence = meetings[0].schedule.recurrence.as_ref().unwrap();
+    assert_eq!(recurrence.frequency, RecurrenceFrequency::Weekly);
+    assert_eq!(recurrence.interval, 2);
+    assert_eq!(
+        recurrence.until,
+        chrono::NaiveDate::from_ymd_opt(2025, 12, 31)
+    );
+    assert_eq!(meetings[0].participants.len(), 2);
+    assert!(meetings[1].schedule.recurrence.is_none());
+}
+
+#[tokio::test]
+async fn test_create_recurring_meeting() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/http/calendars/meetings")
+        .match_body(Matcher::Json(json!({
+            "summary": "Sprint planning",
+            "occurrenceRule": {
+                "start": "2025-06-02T08:00:00Z",
+                "end": "2025-06-02T09:00:00Z",
+                "timezone": { "id": "Europe/Berlin" },
+                "allDay": false,
+                "recurrenceRule": {
+                    "freq": "WEEKLY",
+                    "interval": 2,
+                    "until": "2025-12-31"
+                }
+            },
+            "profiles": [
+                "id:0198ad98-74d8-785e-941b-77f40b4ed03f",
+                "id:0198ad98-74d8-7da6-b193-4779e3d23442"
+            ]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("meeting_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut schedule =
+        MeetingSchedule::new(utc("2025-06-02T08:00:00Z"), utc("2025-06-02T09:00:00Z"));
+    schedule.time_zone = Some("Europe/Berlin".to_string());
+    let mut recurrence = Recurrence::new(RecurrenceFrequency::Weekly);
+    recurrence.interval = 2;
+    recurrence.until = chrono::NaiveDate::from_ymd_opt(2025, 12, 31);
+    schedule.recurrence = Some(recurrence);
+    let mut meeting = NewMeeting::new("Sprint planning", schedule);
+    meeting.participant_ids = vec![
+        "0198ad98-74d8-785e-941b-77f40b4ed03f".to_string(),
+        "0198ad98-74d8-7da6-b193-4779e3d23442".to_string(),
+    ];
+    let created = client.create_meeting(meeting).await.unwrap();
+
+    mock.assert();
+    assert_eq!(created.id, "0198d3a1-1a2b-7c3d-8e4f-5a6b7c8d9e21");
+}
+
+#[tokio::test]
+async fn test_update_and_cancel_meeting() {
+    let mut server = Server::new_async().await;
+
+    let update_mock = server
+        .mock(
+            "PATCH",
+            "/api/http/calendars/meetings/id:0198d3a1-1a2b-7c3d-8e4f-5a6b7c8d9e21",
+        )
+        .match_body(Matcher::Json(json!({
+            "description": "Plan the next two weeks",
+            "profiles": ["id:0198ad98-74d8-785e-941b-77f40b4ed03f"]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("meeting_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let cancel_mock = server
+        .mock(
+            "DELETE",
+            "/api/http/calendars/meetings/id:0198d3a1-1a2b-7c3d-8e4f-5a6b7c8d9e21",
+        )
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let update = MeetingUpdate {
+        description: Some("Plan the next two weeks".to_string()),
+        participant_ids: Some(vec!["0198ad98-74d8-785e-941b-77f40b4ed03f".to_string()]),
+        ..MeetingUpdate::default()
+    };
+    let meeting = client
+        .update_meeting("0198d3a1-1a2b-7c3d-8e4f-5a6b7c8d9e21", update)
+        .await
+        .unwrap();
+    client.cancel_meeting(&meeting.id).await.unwrap();
+
+    update_mock.assert();
+    cancel_mock.assert();
+}
@@ -0,0 +1,204 @@
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{Client, Error, MergeOptions, MergeState, MergeStrategy, NewMergeRequest};
+use utils::load_fixture;
+
+mod utils;
+
+const REVIEW_PATH: &str = "/api/http/projects/id:proj123/code-reviews/id:0198c9a1";
+
+#[tokio::test]
+async fn test_create_merge_request_with_reviewers() {
+    let mut server = Server::new_async().await;
+
+    let create_mock = server
+        .mock(
+            "POST",
+            "/api/http/projects/id:proj123/code-reviews/merge-requests",
+        )
+        .match_header("Authorization", "Bearer test_token")
+        .match_body(Matcher::Json(json!({
+            "repository": "space-rs",
+            "sourceBranch": "release/1.4.0",
+            "targetBranch": "main",
+            "title": "Release 1.4.0",
+            "reviewers": [{ "profileId": "id:0198ad98-74d8-7da6-b193-4779e3d23442" }]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("merge_request_created_response.json").to_string())
+        .create_async()
+        .await;
+    let reviewer_mock = server
+        .mock(
+            "POST",
+            Matcher::Regex(format!("^{REVIEW_PATH}/participants/id:(alice|bob)$")),
+        )
+        .match_body(Matcher::Json(json!({ "role": "Reviewer" })))
+        .with_status(200)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut merge_request = NewMergeRequest::new(
+        "proj123",
+        "space-rs",
+        "release/1.4.0",
+        "main",
+        "Release 1.4.0",
+    );
+    merge_request.reviewers = vec!["0198ad98-74d8-7da6-b193-4779e3d23442".to_string()];
+    let review = client.create_merge_request(merge_request).await.unwrap();
+    client
+        .add_merge_request_reviewers("proj123", "0198c9a1", &["alice", "bob"])
+        .await
+        .unwrap();
+
+    create_mock.assert();
+    reviewer_mock.assert();
+    assert_eq!(review.number, 21);
+    assert_eq!(review.title, "Release 1.4.0");
+}
+
+#[tokio::test]
+async fn test_get_merge_status_with_conflicts() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", format!("{REVIEW_PATH}/merge-status").as_str())
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("merge_status_conflicts_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let status = client
+        .get_merge_status("proj123", "0198c9a1")
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(status.state, MergeState::Conflicts);
+    assert!(!status.is_mergeable());
+    assert_eq!(status.conflicts, vec!["/Cargo.toml", "/src/lib.rs"]);
+}
+
+#[tokio::test]
+async fn test_merge_merge_request_success() {
+    let mut server = Server::new_async().await;
+
+    let status_mock = server
+        .mock("GET", format!("{REVIEW_PATH}/merge-status").as_str())
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("merge_status_mergeable_response.json").to_string())
+        .create_async()
+        .await;
+    let merge_mock = server
+        .mock("PUT", format!("{REVIEW_PATH}/merge").as_str())
+        .match_body(Matcher::Json(json!({
+            "mergeMode": "FF",
+            "squash": true,
+            "squashedCommitMessage": "Release 1.4.0",
+            "deleteSourceBranch": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("merge_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut options = MergeOptions::new(MergeStrategy::Squash);
+    options.delete_source_branch = true;
+    options.commit_message = Some("Release 1.4.0".to_string());
+    let result = client
+        .merge_merge_request("proj123", "0198c9a1", options)
+        .await
+        .unwrap();
+
+    status_mock.assert();
+    merge_mock.assert();
+    assert_eq!(result.commit_id, "9a8b7c6d5e4f30211203f4e5d6c7b8a99a8b7c6d");
+    assert!(result.source_branch_deleted);
+}
+
+#[tokio::test]
+async fn test_merge_merge_request_with_conflicts() {
+    let mut server = Server::new_async().await;
+
+    let status_mock = server
+        .mock("GET", format!("{REVIEW_PATH}/merge-status").as_str())
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("merge_status_conflicts_response.json").to_string())
+        .create_async()
+        .await;
+    let merge_mock = server
+        .mock("PUT", format!("{REVIEW_PATH}/merge").as_str())
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client
+        .merge_merge_request(
+            "proj123",
+            "0198c9a1",
+            MergeOptions::new(MergeStrategy::NoFastForward),
+        )
+        .await;
+
+    status_mock.assert();
+    merge_mock.assert();
+    match result {
+        Err(Error::MergeConflicts(files)) => {
+            assert_eq!(files, vec!["/Cargo.toml", "/src/lib.rs"]);
+        }
+        other => panic!("expected merge conflicts, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_merge_merge_request_rejected() {
+    let mut server = Server::new_async().await;
+
+    let _status_mock = server
+        .mock("GET", format!("{REVIEW_PATH}/merge-status").as_str())
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("merge_status_mergeable_response.json").to_string())
+        .create_async()
+        .await;
+    let merge_mock = server
+        .mock("PUT", format!("{REVIEW_PATH}/merge").as_str())
+        .match_body(Matcher::PartialJson(json!({ "mergeMode": "FF_ONLY" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("merge_rejected_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client
+        .merge_merge_request(
+            "proj123",
+            "0198c9a1",
+            MergeOptions::new(MergeStrategy::FastForwardOnly),
+        )
+        .await;
+
+    merge_mock.assert();
+    match result {
+        Err(Error::MergeRejected(message)) => {
+            assert_eq!(message, "Fast-forward is not possible");
+        }
+        other => panic!("expected a rejected merge, got {other:?}"),
+    }
+}
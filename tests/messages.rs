@@ -0,0 +1,86 @@
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{ChatMessage, Client, MessageSection, MessageStyle};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_send_direct_message_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/http/chats/messages/send-message")
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .match_body(Matcher::Json(json!({
+            "channel": "member:id:0198ad98-74d8-785e-941b-77f40b4ed03f",
+            "content": {
+                "className": "ChatMessage.Text",
+                "text": "Your change broke the build",
+                "markdown": false
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("send_message_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client
+        .send_direct_message(
+            "0198ad98-74d8-785e-941b-77f40b4ed03f",
+            "Your change broke the build",
+        )
+        .await;
+
+    mock.assert();
+    assert!(result.is_ok());
+
+    let message = result.unwrap();
+    assert_eq!(message.id, "0198c7d4-5e6f-7a8b-9c0d-1e2f3a4b5c01");
+    assert_eq!(message.author.username, "CI Bot");
+}
+
+#[tokio::test]
+async fn test_send_direct_message_with_blocks() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/http/chats/messages/send-message")
+        .match_body(Matcher::PartialJson(json!({
+            "channel": "member:id:0198ad98-74d8-785e-941b-77f40b4ed03f",
+            "content": {
+                "className": "ChatMessage.Block",
+                "style": "WARNING",
+                "sections": [{
+                    "className": "MessageSection",
+                    "header": "Flaky test",
+                    "elements": [{
+                        "className": "MessageText",
+                        "content": "`parser::tests::unicode` failed twice",
+                        "accent": false
+                    }]
+                }]
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("send_message_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let message = ChatMessage::block().style(MessageStyle::Warning).section(
+        MessageSection::new()
+            .header("Flaky test")
+            .text("`parser::tests::unicode` failed twice"),
+    );
+    let result = client
+        .send_direct_message("0198ad98-74d8-785e-941b-77f40b4ed03f", message)
+        .await;
+
+    mock.assert();
+    assert!(result.is_ok());
+}
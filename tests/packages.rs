@@ -0,0 +1,185 @@
+use mockito::{Matcher, Server};
+use space::{Client, PackageType};
+use utils::load_fixture;
+
+mod utils;
+
+const REPOSITORY_PATH: &str =
+    "/api/http/projects/id:proj123/packages/repositories/id:0198d2a1-bbbb-7b2c-8d3e-4f5a6b7c8d02";
+
+#[tokio::test]
+async fn test_get_package_repositories_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/projects/id:proj123/packages/repositories")
+        .match_query(Matcher::Any)
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("package_repositories_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let repositories = client.get_package_repositories("proj123").await.unwrap();
+
+    mock.assert();
+    assert_eq!(repositories.len(), 2);
+    assert_eq!(repositories[0].package_type, PackageType::Cargo);
+    assert_eq!(repositories[1].package_type, PackageType::Container);
+    assert!(repositories[1].public);
+    assert!(repositories[1].description.is_none());
+}
+
+#[tokio::test]
+async fn test_get_packages_and_versions() {
+    let mut server = Server::new_async().await;
+
+    let packages_mock = server
+        .mock("GET", format!("{REPOSITORY_PATH}/packages").as_str())
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("packages_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let versions_mock = server
+        .mock(
+            "GET",
+            format!("{REPOSITORY_PATH}/packages/space-bot/versions").as_str(),
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("package_versions_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let packages = client
+        .get_packages("proj123", "0198d2a1-bbbb-7b2c-8d3e-4f5a6b7c8d02")
+        .await
+        .unwrap();
+    let versions = client
+        .get_package_versions(
+            "proj123",
+            "0198d2a1-bbbb-7b2c-8d3e-4f5a6b7c8d02",
+            &packages[0].name,
+        )
+        .await
+        .unwrap();
+
+    packages_mock.assert();
+    versions_mock.assert();
+    assert_eq!(packages.len(), 1);
+    assert_eq!(
+        packages[0].updated_at,
+        Some(
+            chrono::DateTime::parse_from_rfc3339("2025-06-04T12:00:00Z")
+                .unwrap()
+                .into()
+        )
+    );
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[0].disk_size, 48_211_968);
+    assert_eq!(versions[1].downloads, 0);
+    assert!(versions[1].accessed_at.is_none());
+}
+
+#[tokio::test]
+async fn test_get_package_version_metadata() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "GET",
+            format!("{REPOSITORY_PATH}/packages/space-bot/versions/1.4.0").as_str(),
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("package_version_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let details = client
+        .get_package_version(
+            "proj123",
+            "0198d2a1-bbbb-7b2c-8d3e-4f5a6b7c8d02",
+            "space-bot",
+            "1.4.0",
+        )
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(details.version.version, "1.4.0");
+    assert_eq!(details.tags, vec!["1.4.0", "latest"]);
+    assert_eq!(details.metadata["branch"], "release/1.4.0");
+}
+
+#[tokio::test]
+async fn test_delete_package_version_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "DELETE",
+            format!("{REPOSITORY_PATH}/packages/space-bot/versions/1.3.0").as_str(),
+        )
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client
+        .delete_package_version(
+            "proj123",
+            "0198d2a1-bbbb-7b2c-8d3e-4f5a6b7c8d02",
+            "space-bot",
+            "1.3.0",
+        )
+        .await;
+
+    mock.assert();
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_delete_namespaced_package_version() {
+    let mut server = Server::new_async().await;
+
+    let container_mock = server
+        .mock(
+            "DELETE",
+            format!("{REPOSITORY_PATH}/packages/team%2Fapp/versions/1.3.0%2Bbuild.7").as_str(),
+        )
+        .with_status(200)
+        .create_async()
+        .await;
+    let npm_mock = server
+        .mock(
+            "DELETE",
+            format!("{REPOSITORY_PATH}/packages/%40scope%2Fpkg/versions/2.0.0").as_str(),
+        )
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let repository_id = "0198d2a1-bbbb-7b2c-8d3e-4f5a6b7c8d02";
+    let container = client
+        .delete_package_version("proj123", repository_id, "team/app", "1.3.0+build.7")
+        .await;
+    let npm = client
+        .delete_package_version("proj123", repository_id, "@scope/pkg", "2.0.0")
+        .await;
+
+    container_mock.assert();
+    npm_mock.assert();
+    assert!(container.is_ok());
+    assert!(npm.is_ok());
+}
@@ -0,0 +1,112 @@
+use chrono::{NaiveDate, TimeZone, Utc, Weekday};
+use mockito::{Matcher, Mock, Server, ServerGuard};
+use space::{Client, Member};
+use utils::load_fixture;
+
+mod utils;
+
+const BERLIN_ID: &str = "0198ad98-74d8-785e-941b-77f40b4ed03f";
+const NEW_YORK_ID: &str = "0198ad98-74d8-7da6-b193-4779e3d23442";
+
+async fn mock_profile(server: &mut ServerGuard, profile_id: &str, name: &str) -> (Mock, Mock) {
+    let profile = server
+        .mock(
+            "GET",
+            format!("/api/http/team-directory/profiles/id:{profile_id}").as_str(),
+        )
+        .match_query(Matcher::Any)
+        .match_header("Authorization", "Bearer test_token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture(&format!("profile_{name}_response.json")).to_string())
+        .create_async()
+        .await;
+    let working_days = server
+        .mock(
+            "GET",
+            format!("/api/http/team-directory/profiles/id:{profile_id}/working-days").as_str(),
+        )
+        .match_query(Matcher::Any)
+        .match_header("Authorization", "Bearer test_token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture(&format!("working_days_{name}_response.json")).to_string())
+        .create_async()
+        .await;
+
+    (profile, working_days)
+}
+
+#[tokio::test]
+async fn test_get_profile_success() {
+    let mut server = Server::new_async().await;
+    let (profile_mock, working_days_mock) = mock_profile(&mut server, BERLIN_ID, "berlin").await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client.get_profile(BERLIN_ID).await;
+
+    profile_mock.assert();
+    working_days_mock.assert();
+    assert!(result.is_ok());
+
+    let profile = result.unwrap();
+    let date = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+    assert_eq!(profile.username, "best.programmer");
+    assert_eq!(profile.location_on(date).unwrap().name, "Berlin");
+    assert_eq!(profile.time_zone_on(date), Some(chrono_tz::Europe::Berlin));
+    assert!(
+        profile
+            .location_on(NaiveDate::from_ymd_opt(2023, 7, 1).unwrap())
+            .is_none()
+    );
+
+    assert_eq!(profile.working_days.len(), 1);
+    let days = &profile.working_days[0].days;
+    assert_eq!(days.len(), 7);
+    assert_eq!(days[0].day, Weekday::Mon);
+    assert!(days[0].working);
+    assert!(!days[6].working);
+}
+
+#[tokio::test]
+async fn test_get_overlapping_working_hours() {
+    let mut server = Server::new_async().await;
+    let _berlin = mock_profile(&mut server, BERLIN_ID, "berlin").await;
+    let _new_york = mock_profile(&mut server, NEW_YORK_ID, "new_york").await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let members = vec![
+        Member {
+            id: BERLIN_ID.to_string(),
+            username: "best.programmer".to_string(),
+        },
+        Member {
+            id: NEW_YORK_ID.to_string(),
+            username: "some.teamlead".to_string(),
+        },
+    ];
+
+    // Tuesday: Berlin works 07:00-16:00 UTC, New York 13:00-21:00 UTC.
+    let windows = client
+        .get_overlapping_working_hours(&members, NaiveDate::from_ymd_opt(2025, 7, 1).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(windows.len(), 1);
+    assert_eq!(
+        windows[0].start,
+        Utc.with_ymd_and_hms(2025, 7, 1, 13, 0, 0).unwrap()
+    );
+    assert_eq!(
+        windows[0].end,
+        Utc.with_ymd_and_hms(2025, 7, 1, 16, 0, 0).unwrap()
+    );
+
+    // Saturday: nobody works.
+    let windows = client
+        .get_overlapping_working_hours(&members, NaiveDate::from_ymd_opt(2025, 7, 5).unwrap())
+        .await
+        .unwrap();
+
+    assert!(windows.is_empty());
+}
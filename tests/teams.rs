@@ -0,0 +1,140 @@
+use mockito::{Matcher, Server};
+use space::{Client, TeamsQuery};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_get_team_tree_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/team-directory/teams")
+        .match_query(Matcher::Any)
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("teams_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client.get_team_tree(TeamsQuery::new()).await;
+
+    mock.assert();
+    assert!(result.is_ok());
+
+    let tree = result.unwrap();
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].team.name, "Organization");
+    assert!(tree[0].team.parent_id.is_none());
+    assert_eq!(tree[0].children.len(), 1);
+
+    let engineering = &tree[0].children[0];
+    assert_eq!(engineering.team.name, "Engineering");
+    assert_eq!(
+        engineering.team.description,
+        Some("Everyone who ships code".to_string())
+    );
+    assert_eq!(engineering.children.len(), 1);
+    assert_eq!(engineering.children[0].team.name, "Backend");
+    assert!(engineering.children[0].children.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_team_members_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/team-directory/memberships")
+        .match_query(Matcher::UrlEncoded(
+            "teams".into(),
+            "0198c1a2-5b10-74a8-b3f6-0e9d7c5a4b03".into(),
+        ))
+        .match_header("Authorization", "Bearer test_token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("memberships_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client
+        .get_team_members("0198c1a2-5b10-74a8-b3f6-0e9d7c5a4b03")
+        .await;
+
+    mock.assert();
+    assert!(result.is_ok());
+
+    let memberships = result.unwrap();
+    assert_eq!(memberships.len(), 2);
+    assert_eq!(memberships[0].member.username, "best.programmer");
+    assert_eq!(memberships[0].team.name, "Backend");
+    assert_eq!(memberships[0].role, Some("Developer".to_string()));
+    assert!(!memberships[0].lead);
+    assert_eq!(
+        memberships[0].manager.as_ref().unwrap().username,
+        "some.teamlead"
+    );
+    assert_eq!(
+        memberships[0].since,
+        Some(
+            chrono::DateTime::parse_from_rfc3339("2024-03-01T00:00:00Z")
+                .unwrap()
+                .into()
+        )
+    );
+    assert!(memberships[0].till.is_none());
+    assert!(memberships[1].lead);
+    assert!(memberships[1].manager.is_none());
+}
+
+#[tokio::test]
+async fn test_get_team_managers_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/team-directory/memberships")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("memberships_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let managers = client
+        .get_team_managers("0198c1a2-5b10-74a8-b3f6-0e9d7c5a4b03")
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(managers.len(), 1);
+    assert_eq!(managers[0].member.username, "some.teamlead");
+}
+
+#[tokio::test]
+async fn test_get_profile_memberships_uses_profile_identifier() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/team-directory/memberships")
+        .match_query(Matcher::UrlEncoded(
+            "profiles".into(),
+            "id:0198ad98-74d8-785e-941b-77f40b4ed03f".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("memberships_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client
+        .get_profile_memberships("0198ad98-74d8-785e-941b-77f40b4ed03f")
+        .await;
+
+    mock.assert();
+    assert!(result.is_ok());
+}
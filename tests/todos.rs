@@ -0,0 +1,98 @@
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{Client, NewTodoItem, TodoItemUpdate, TodoItemsQuery};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_get_open_todo_items() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/todo")
+        .match_query(Matcher::UrlEncoded("open".into(), "true".into()))
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("todo_items_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let items = client.get_todo_items(TodoItemsQuery::open()).await.unwrap();
+
+    mock.assert();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].text, "Review release notes");
+    assert!(!items[0].is_done());
+    assert_eq!(
+        items[0].due_date,
+        chrono::NaiveDate::from_ymd_opt(2025, 6, 5)
+    );
+    assert!(items[1].due_date.is_none());
+}
+
+#[tokio::test]
+async fn test_create_update_and_complete_todo_item() {
+    let mut server = Server::new_async().await;
+
+    let create_mock = server
+        .mock("POST", "/api/http/todo")
+        .match_body(Matcher::Json(json!({
+            "text": "Review release notes",
+            "dueDate": "2025-06-05"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("todo_item_positive_response.json").to_string())
+        .create_async()
+        .await;
+    let update_mock = server
+        .mock("PATCH", "/api/http/todo/2Lr9Ga0Ab1Cd")
+        .match_body(Matcher::Json(
+            json!({ "text": "Review and publish release notes" }),
+        ))
+        .with_status(200)
+        .create_async()
+        .await;
+    let complete_mock = server
+        .mock("PATCH", "/api/http/todo/2Lr9Ga0Ab1Cd")
+        .match_body(Matcher::Json(json!({ "open": false })))
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let mut new_item = NewTodoItem::new("Review release notes");
+    new_item.due_date = chrono::NaiveDate::from_ymd_opt(2025, 6, 5);
+    let item = client.create_todo_item(new_item).await.unwrap();
+    let update = TodoItemUpdate {
+        text: Some("Review and publish release notes".to_string()),
+        ..TodoItemUpdate::default()
+    };
+    client.update_todo_item(&item.id, update).await.unwrap();
+    client.complete_todo_item(&item.id).await.unwrap();
+
+    create_mock.assert();
+    update_mock.assert();
+    complete_mock.assert();
+}
+
+#[tokio::test]
+async fn test_delete_todo_item_success() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("DELETE", "/api/http/todo/3Ms0Hb1Bc2De")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client.delete_todo_item("3Ms0Hb1Bc2De").await;
+
+    mock.assert();
+    assert!(result.is_ok());
+}
@@ -0,0 +1,118 @@
+use chrono::DateTime;
+use hmac::{Hmac, Mac};
+use mockito::Server;
+use sha2::Sha256;
+use space::{
+    Client, Error, SIGNATURE_HEADER, TIMESTAMP_HEADER, WebhookEvent, WebhookVerifier,
+    parse_webhook_payload,
+};
+use utils::load_fixture;
+
+mod utils;
+
+fn sign(key: &str, timestamp: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key.as_bytes()).unwrap();
+    mac.update(format!("{timestamp}:").as_bytes());
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+#[test]
+fn test_verify_and_parse_issue_created() {
+    let body = load_fixture("webhook_issue_created_payload.json").to_string();
+    let signature = sign("signing-key", "1761122211000", body.as_bytes());
+    let header = |name: &str| match name {
+        TIMESTAMP_HEADER => Some("1761122211000"),
+        SIGNATURE_HEADER => Some(signature.as_str()),
+        _ => None,
+    };
+
+    let payload = WebhookVerifier::signing_key("signing-key")
+        .verify_and_parse(
+            header,
+            body.as_bytes(),
+            DateTime::from_timestamp_millis(1_761_122_215_000).unwrap(),
+        )
+        .unwrap();
+
+    assert_eq!(payload.webhook_id, "4Nt1Ic2Cd3Ef");
+    let WebhookEvent::IssueCreated { issue, actor, at } = payload.event else {
+        panic!("expected an issue created event");
+    };
+    assert_eq!(issue.number, 1);
+    assert_eq!(issue.title, "Test title for first issue");
+    assert_eq!(actor.id, "0198ad98-1274-7980-a83a-8e6036fd17bb");
+    assert_eq!(at.timestamp_millis(), 1_761_122_210_657);
+}
+
+#[test]
+fn test_verify_rejects_tampered_body() {
+    let body = load_fixture("webhook_issue_created_payload.json").to_string();
+    let signature = sign("signing-key", "1761122211000", body.as_bytes());
+    let header = |name: &str| match name {
+        TIMESTAMP_HEADER => Some("1761122211000"),
+        SIGNATURE_HEADER => Some(signature.as_str()),
+        _ => None,
+    };
+    let tampered = body.replace("Test title", "Other title");
+
+    let result = WebhookVerifier::signing_key("signing-key").verify_and_parse(
+        header,
+        tampered.as_bytes(),
+        DateTime::from_timestamp_millis(1_761_122_215_000).unwrap(),
+    );
+
+    assert!(matches!(result, Err(Error::WebhookSignature(_))));
+}
+
+#[test]
+fn test_parse_message_posted() {
+    let body = load_fixture("webhook_message_posted_payload.json").to_string();
+
+    let payload = parse_webhook_payload(body.as_bytes()).unwrap();
+
+    let WebhookEvent::MessagePosted {
+        channel_id,
+        message,
+        ..
+    } = payload.event
+    else {
+        panic!("expected a message posted event");
+    };
+    assert_eq!(channel_id, "0198c6b3-0000-7000-8000-000000000001");
+    assert_eq!(message.text, "Deploy finished");
+    assert_eq!(message.author.username, "Best Programmer");
+}
+
+#[test]
+fn test_parse_unknown_and_invalid_payloads() {
+    let body = load_fixture("webhook_unknown_payload.json").to_string();
+
+    let payload = parse_webhook_payload(body.as_bytes()).unwrap();
+
+    assert!(matches!(payload.event, WebhookEvent::Unknown));
+    assert!(matches!(
+        parse_webhook_payload(b"not json"),
+        Err(Error::WebhookPayload(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_get_space_public_keys() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/applications/public-keys")
+        .match_header("Authorization", "Bearer test_token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("space_public_keys_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let key_set = client.get_space_public_keys().await.unwrap();
+
+    mock.assert();
+    assert!(WebhookVerifier::public_keys(&key_set).is_ok());
+}
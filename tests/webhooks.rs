@@ -0,0 +1,290 @@
+use mockito::{Matcher, Server};
+use serde_json::json;
+use space::{
+    ApplicationIdentifier, Client, Error, EventFilter, NewSubscription, NewWebhook,
+    SubscriptionUpdate, WebhookEventType, WebhookUpdate,
+};
+use utils::load_fixture;
+
+mod utils;
+
+#[tokio::test]
+async fn test_get_webhooks_with_subscriptions() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/http/applications/me/webhooks")
+        .match_query(Matcher::Any)
+        .match_header("Authorization", "Bearer test_token")
+        .match_header("Accept", "application/json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("webhooks_positive_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let webhooks = client
+        .get_webhooks(&ApplicationIdentifier::Me)
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(webhooks.len(), 1);
+    assert_eq!(webhooks[0].url, "https://bridge.example.com/space");
+    let subscription = &webhooks[0].subscriptions[0];
+    assert_eq!(
+        subscription.events,
+        vec![
+            WebhookEventType::IssueCreated,
+            WebhookEventType::IssueUpdated
+        ]
+    );
+    assert_eq!(
+        subscription.filters,
+        vec![EventFilter::Project {
+            project_id: "proj123".to_string()
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_create_webhook_and_subscription() {
+    let mut server = Server::new_async().await;
+
+    let webhook_mock = server
+        .mock("POST", "/api/http/applications/clientId:bridge/webhooks")
+        .match_body(Matcher::Json(json!({
+            "name": "Issue sync",
+            "endpoint": {
+                "url": "https://bridge.example.com/space",
+                "sslVerification": true
+            },
+            "enabled": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("webhook_created_response.json").to_string())
+        .create_async()
+        .await;
+    let subscription_mock = server
+        .mock(
+            "POST",
+            "/api/http/applications/clientId:bridge/webhooks/4Nt1Ic2Cd3Ef/subscriptions",
+        )
+        .match_body(Matcher::Json(json!({
+            "name": "Review activity",
+            "subscription": {
+                "subjectCode": "CodeReview",
+                "filters": [{
+                    "className": "RepositoryFilterIn",
+                    "project": "id:proj123",
+                    "repository": "space-rs"
+                }],
+                "eventTypeCodes": ["CodeReview.Created", "CodeReview.Participant.Changed"]
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(load_fixture("subscription_created_response.json").to_string())
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let application = ApplicationIdentifier::ClientId("bridge".to_string());
+    let webhook = client
+        .create_webhook(
+            &application,
+            NewWebhook::new("Issue sync", "https://bridge.example.com/space"),
+        )
+        .await
+        .unwrap();
+    let mut subscription = NewSubscription::new(
+        "Review activity",
+        &[
+            WebhookEventType::CodeReviewCreated,
+            WebhookEventType::CodeReviewParticipantChanged,
+        ],
+    );
+    subscription.filters = vec![EventFilter::Repository {
+        project_id: "proj123".to_string(),
+        repository: "space-rs".to_string(),
+    }];
+    let subscription = client
+        .create_subscription(&application, &webhook.id, subscription)
+        .await
+        .unwrap();
+
+    webhook_mock.assert();
+    subscription_mock.assert();
+    assert_eq!(subscription.id, "6Pv3Ke4Ef5Gh");
+    assert_eq!(subscription.events.len(), 2);
+}
+
+#[tokio::test]
+async fn test_create_subscription_with_mixed_subjects() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "POST",
+            "/api/http/applications/me/webhooks/4Nt1Ic2Cd3Ef/subscriptions",
+        )
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let result = client
+        .create_subscription(
+            &ApplicationIdentifier::Me,
+            "4Nt1Ic2Cd3Ef",
+            NewSubscription::new(
+                "Everything",
+                &[
+                    WebhookEventType::IssueCreated,
+                    WebhookEventType::MessagePosted,
+                ],
+            ),
+        )
+        .await;
+
+    mock.assert();
+    assert!(matches!(result, Err(Error::InvalidSubscription(_))));
+}
+
+#[tokio::test]
+async fn test_update_and_delete_webhook_and_subscription() {
+    let mut server = Server::new_async().await;
+
+    let webhook_path = "/api/http/applications/id:app42/webhooks/4Nt1Ic2Cd3Ef";
+    let subscription_path = format!("{webhook_path}/subscriptions/5Ou2Jd3De4Fg");
+
+    let update_webhook_mock = server
+        .mock("PATCH", webhook_path)
+        .match_body(Matcher::Json(json!({ "enabled": false })))
+        .with_status(200)
+        .create_async()
+        .await;
+    let update_endpoint_mock = server
+        .mock("PATCH", webhook_path)
+        .match_body(Matcher::Json(json!({
+            "endpoint": { "sslVerification": false }
+        })))
+        .with_status(200)
+        .create_async()
+        .await;
+    let subscriptions_mock = server
+        .mock("GET", format!("{webhook_path}/subscriptions").as_str())
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "data": load_fixture("webhooks_positive_response.json")["data"][0]["subscriptions"]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let update_subscription_mock = server
+        .mock("PATCH", subscription_path.as_str())
+        .match_body(Matcher::Json(json!({
+            "subscription": {
+                "subjectCode": "Issue",
+                "filters": [{ "className": "ProjectFilterIn", "project": "id:proj123" }],
+                "eventTypeCodes": ["Issue.Deleted"]
+            }
+        })))
+        .with_status(200)
+        .create_async()
+        .await;
+    let delete_subscription_mock = server
+        .mock("DELETE", subscription_path.as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+    let delete_webhook_mock = server
+        .mock("DELETE", webhook_path)
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    let application = ApplicationIdentifier::Id("app42".to_string());
+    client
+        .update_webhook(
+            &application,
+            "4Nt1Ic2Cd3Ef",
+            WebhookUpdate {
+                enabled: Some(false),
+                ..WebhookUpdate::default()
+            },
+        )
+        .await
+        .unwrap();
+    client
+        .update_webhook(
+            &application,
+            "4Nt1Ic2Cd3Ef",
+            WebhookUpdate {
+                verify_ssl: Some(false),
+                ..WebhookUpdate::default()
+            },
+        )
+        .await
+        .unwrap();
+    client
+        .update_subscription(
+            &application,
+            "4Nt1Ic2Cd3Ef",
+            "5Ou2Jd3De4Fg",
+            SubscriptionUpdate {
+                events: Some(vec![WebhookEventType::IssueDeleted]),
+                ..SubscriptionUpdate::default()
+            },
+        )
+        .await
+        .unwrap();
+    client
+        .delete_subscription(&application, "4Nt1Ic2Cd3Ef", "5Ou2Jd3De4Fg")
+        .await
+        .unwrap();
+    client
+        .delete_webhook(&application, "4Nt1Ic2Cd3Ef")
+        .await
+        .unwrap();
+
+    update_webhook_mock.assert();
+    update_endpoint_mock.assert();
+    subscriptions_mock.assert();
+    update_subscription_mock.assert();
+    delete_subscription_mock.assert();
+    delete_webhook_mock.assert();
+}
+
+#[tokio::test]
+async fn test_webhook_paths_are_encoded() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "DELETE",
+            "/api/http/applications/clientId:deploy%20bot/webhooks/hook%2F1/subscriptions/sub%3F2",
+        )
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), "test_token", None);
+    client
+        .delete_subscription(
+            &ApplicationIdentifier::ClientId("deploy bot".to_string()),
+            "hook/1",
+            "sub?2",
+        )
+        .await
+        .unwrap();
+
+    mock.assert();
+}